@@ -0,0 +1,113 @@
+mod common;
+
+use common::{Columns, candles};
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+use trading_toolkit::indicator::{
+    AverageTrueRange, Channel, MovingAverage, ParabolicSar, StochasticWindow, WilliamsR,
+};
+
+const LEN: usize = 100_000;
+const PERIOD: usize = 20;
+
+/// the same series computed by the generic functions bar by bar, and by the columnar functions
+fn columnar(c: &mut Criterion) {
+    let data = candles(LEN);
+    let columns = Columns::new(&data);
+    let columns = columns.columns();
+
+    let mut group = c.benchmark_group("sma");
+    group.bench_function(BenchmarkId::new("generic", LEN), |b| {
+        b.iter(|| {
+            let mut sma = MovingAverage::simple(&data[..PERIOD]);
+            let mut result = vec![sma.inner()];
+            for i in PERIOD..data.len() {
+                sma = MovingAverage::simple_from(PERIOD, &sma, &data[i - PERIOD], &data[i]);
+                result.push(sma.inner());
+            }
+            black_box(result)
+        })
+    });
+    group.bench_function(BenchmarkId::new("columnar", LEN), |b| {
+        b.iter(|| black_box(MovingAverage::simple_columnar(columns.close(), PERIOD).unwrap()))
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("atr");
+    group.bench_function(BenchmarkId::new("generic", LEN), |b| {
+        b.iter(|| black_box(AverageTrueRange::series(&data, 14).unwrap()))
+    });
+    group.bench_function(BenchmarkId::new("columnar", LEN), |b| {
+        b.iter(|| black_box(AverageTrueRange::columnar(&columns, 14).unwrap()))
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("parabolic_sar");
+    group.bench_function(BenchmarkId::new("generic", LEN), |b| {
+        b.iter(|| black_box(ParabolicSar::series(&data, 0.02, 0.2).unwrap()))
+    });
+    group.bench_function(BenchmarkId::new("columnar", LEN), |b| {
+        b.iter(|| black_box(ParabolicSar::columnar(&columns, 0.02, 0.2).unwrap()))
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("stochastic");
+    group.bench_function(BenchmarkId::new("generic", LEN), |b| {
+        b.iter(|| {
+            let mut window = StochasticWindow::new(&data[..16], 14, 3).unwrap();
+            let mut result = vec![window.slow().inner()];
+            for elem in data.iter().skip(16) {
+                window = StochasticWindow::from_prev(&window, elem);
+                result.push(window.slow().inner());
+            }
+            black_box(result)
+        })
+    });
+    group.bench_function(BenchmarkId::new("columnar", LEN), |b| {
+        b.iter(|| black_box(StochasticWindow::columnar(&columns, 14, 3).unwrap()))
+    });
+    group.finish();
+
+    // generic 함수는 window마다 새로 계산
+    let mut group = c.benchmark_group("williams_r");
+    group.bench_function(BenchmarkId::new("generic", LEN), |b| {
+        b.iter(|| {
+            let result: Vec<f64> = data
+                .windows(PERIOD)
+                .map(|window| {
+                    WilliamsR::new(window, PERIOD)
+                        .map(|r| r.inner())
+                        .unwrap_or(f64::NAN)
+                })
+                .collect();
+            black_box(result)
+        })
+    });
+    group.bench_function(BenchmarkId::new("columnar", LEN), |b| {
+        b.iter(|| black_box(WilliamsR::columnar(&columns, PERIOD).unwrap()))
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("bollinger");
+    group.bench_function(BenchmarkId::new("generic", LEN), |b| {
+        b.iter(|| {
+            let result: Vec<f64> = data
+                .windows(PERIOD)
+                .map(|window| {
+                    Channel::bollinger(window, 2f64, false)
+                        .unwrap()
+                        .inner()
+                        .upper
+                })
+                .collect();
+            black_box(result)
+        })
+    });
+    group.bench_function(BenchmarkId::new("columnar", LEN), |b| {
+        b.iter(|| black_box(Channel::bollinger_columnar(columns.close(), PERIOD, 2f64).unwrap()))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, columnar);
+criterion_main!(benches);
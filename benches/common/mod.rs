@@ -0,0 +1,61 @@
+use trading_toolkit::types::data::{CandleColumns, Ohlcv};
+
+/// reproducible 1-minute random walk of `len` bars
+pub fn candles(len: usize) -> Vec<Ohlcv> {
+    let mut seed = 0x2545_f491_4f6c_dd1du64;
+    let mut close = 100f64;
+    (0..len)
+        .map(|i| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            let change = (seed % 2001) as f64 / 1000f64 - 1f64;
+            let open = close;
+            close = (close + change).max(1f64);
+            Ohlcv::new(
+                open,
+                open.max(close) + (seed % 50) as f64 / 100f64,
+                open.min(close) - (seed % 37) as f64 / 100f64,
+                close,
+                1000 + seed % 5000,
+                i as u128 * 60_000,
+            )
+        })
+        .collect()
+}
+
+/// owned columns of candles, borrowed by `CandleColumns`
+pub struct Columns {
+    open: Vec<f64>,
+    high: Vec<f64>,
+    low: Vec<f64>,
+    close: Vec<f64>,
+    volume: Vec<u64>,
+    epoch_times: Vec<u128>,
+}
+
+impl Columns {
+    pub fn new(data: &[Ohlcv]) -> Self {
+        Self {
+            open: data.iter().map(|elem| elem.open).collect(),
+            high: data.iter().map(|elem| elem.high).collect(),
+            low: data.iter().map(|elem| elem.low).collect(),
+            close: data.iter().map(|elem| elem.close).collect(),
+            volume: data.iter().map(|elem| elem.volume).collect(),
+            epoch_times: data.iter().map(|elem| elem.epoch_time).collect(),
+        }
+    }
+
+    pub fn columns(&self) -> CandleColumns<'_> {
+        // it's safe since the candles are generated in order
+        CandleColumns::new(
+            &self.open,
+            &self.high,
+            &self.low,
+            &self.close,
+            &self.volume,
+            &self.epoch_times,
+        )
+        .unwrap()
+    }
+}
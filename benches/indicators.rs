@@ -0,0 +1,504 @@
+mod common;
+
+use common::{Columns, candles};
+use criterion::{
+    BenchmarkGroup, BenchmarkId, Criterion, criterion_group, criterion_main, measurement::WallTime,
+};
+use std::hint::black_box;
+use trading_toolkit::indicator::{
+    AccumulationDistribution, AverageTrueRange, ChaikinMoneyFlow, ChaikinOscillator, Channel,
+    CommodityChannelIndex, ElderRay, ForceIndex, Ichimoku, Momentum, MoneyFlowIndex, MovingAverage,
+    MovingAverageConvergenceDivergence, OnBalanceVolume, ParabolicSar, Pivot, RateOfChange,
+    StochasticWindow, SuperTrend, SwingPoint, Trix, UltimateOscillator, Volatility, WilliamsR,
+    ZigZag,
+};
+use trading_toolkit::stats::LinearRegression;
+use trading_toolkit::types::{
+    data::{CandleColumns, Ohlcv},
+    error::ToolkitError,
+    time::Time,
+};
+
+const PERIOD: usize = 14;
+/// bars of the 10M series, benchmarked with fewer samples
+/// since the candles and their sorted copies take a few GB
+const LARGE: usize = 10_000_000;
+
+type Generic = fn(&[Ohlcv], Time) -> Result<Volatility, ToolkitError>;
+type Columnar = fn(&CandleColumns, usize, Time) -> Result<Vec<f64>, ToolkitError>;
+
+const SIZES: [usize; 3] = [1_000, 100_000, LARGE];
+
+fn open_group<'a>(c: &'a mut Criterion, name: &str, len: usize) -> BenchmarkGroup<'a, WallTime> {
+    let mut group = c.benchmark_group(name);
+    if len >= LARGE {
+        group.sample_size(10);
+    }
+    group
+}
+
+fn bench<R, F>(group: &mut BenchmarkGroup<WallTime>, mode: &str, len: usize, mut f: F)
+where
+    F: FnMut() -> R,
+{
+    group.bench_function(BenchmarkId::new(mode, len), |b| b.iter(|| black_box(f())));
+}
+
+/// every indicator over 1k/100k/10M bars in up to 3 modes
+/// - batch: `new` over the whole series
+/// - series: every bar at once, `series` if the indicator has one, else `columnar`
+/// - incremental: `from_prev` bar by bar, only for indicators with `from_prev`
+///
+/// event indicators(pivot, swing, zigzag) have no incremental mode, their series mode is the columnar one
+fn indicators(c: &mut Criterion) {
+    for len in SIZES {
+        let data = candles(len);
+        let owned = Columns::new(&data);
+        let columns = owned.columns();
+        moving_average(c, &data, &columns);
+        macd(c, &data, &columns);
+        trix(c, &data, &columns);
+        momentum(c, &data, &columns);
+        channel(c, &data, &columns);
+        oscillators(c, &data, &columns);
+        volume(c, &data, &columns);
+        trend(c, &data, &columns);
+        volatility(c, &data, &columns);
+        events(c, &data, &columns);
+    }
+}
+
+fn moving_average(c: &mut Criterion, data: &[Ohlcv], columns: &CandleColumns) {
+    let len = data.len();
+    let mut group = open_group(c, "sma", len);
+    bench(&mut group, "batch", len, || MovingAverage::simple(data));
+    bench(&mut group, "series", len, || {
+        MovingAverage::simple_columnar(columns.close(), PERIOD).unwrap()
+    });
+    bench(&mut group, "incremental", len, || {
+        let mut sma = MovingAverage::simple(&data[..PERIOD]);
+        let mut result = vec![sma.inner()];
+        for i in PERIOD..len {
+            sma = MovingAverage::simple_from(PERIOD, &sma, &data[i - PERIOD], &data[i]);
+            result.push(sma.inner());
+        }
+        result
+    });
+    group.finish();
+
+    let mut group = open_group(c, "ema", len);
+    bench(&mut group, "batch", len, || {
+        MovingAverage::exponential(data)
+    });
+    bench(&mut group, "series", len, || {
+        MovingAverage::exponential_columnar(columns.close(), PERIOD).unwrap()
+    });
+    bench(&mut group, "incremental", len, || {
+        let mut ema = MovingAverage::simple(&data[..PERIOD]);
+        let mut result = vec![ema.inner()];
+        for elem in data.iter().skip(PERIOD) {
+            ema = MovingAverage::exponential_from(PERIOD, &ema, elem);
+            result.push(ema.inner());
+        }
+        result
+    });
+    group.finish();
+}
+
+fn macd(c: &mut Criterion, data: &[Ohlcv], columns: &CandleColumns) {
+    let len = data.len();
+    let mut group = open_group(c, "macd", len);
+    bench(&mut group, "batch", len, || {
+        MovingAverageConvergenceDivergence::new(data).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        MovingAverageConvergenceDivergence::columnar(columns.close()).unwrap()
+    });
+    bench(&mut group, "incremental", len, || {
+        let mut macd = MovingAverageConvergenceDivergence::new(&data[..34]).unwrap();
+        let mut result = vec![macd.macd_histogram()];
+        for elem in data.iter().skip(34) {
+            macd = MovingAverageConvergenceDivergence::from_prev(&macd, elem);
+            result.push(macd.macd_histogram());
+        }
+        result
+    });
+    group.finish();
+}
+
+fn trix(c: &mut Criterion, data: &[Ohlcv], columns: &CandleColumns) {
+    let len = data.len();
+    let warmup = 3 * (PERIOD - 1) + 2;
+    let mut group = open_group(c, "trix", len);
+    bench(&mut group, "batch", len, || {
+        Trix::new(data, PERIOD).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        Trix::columnar(columns.close(), PERIOD).unwrap()
+    });
+    bench(&mut group, "incremental", len, || {
+        let mut trix = Trix::new(&data[..warmup], PERIOD).unwrap();
+        let mut result = vec![trix.inner()];
+        for elem in data.iter().skip(warmup) {
+            trix = Trix::from_prev(PERIOD, &trix, elem);
+            result.push(trix.inner());
+        }
+        result
+    });
+    group.finish();
+}
+
+fn momentum(c: &mut Criterion, data: &[Ohlcv], columns: &CandleColumns) {
+    let len = data.len();
+    let mut group = open_group(c, "momentum", len);
+    bench(&mut group, "batch", len, || {
+        Momentum::new(data, PERIOD).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        Momentum::columnar(columns.close(), PERIOD).unwrap()
+    });
+    group.finish();
+
+    let mut group = open_group(c, "rate_of_change", len);
+    bench(&mut group, "batch", len, || {
+        RateOfChange::new(data, PERIOD).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        RateOfChange::columnar(columns.close(), PERIOD).unwrap()
+    });
+    group.finish();
+}
+
+fn channel(c: &mut Criterion, data: &[Ohlcv], columns: &CandleColumns) {
+    let len = data.len();
+    let mut group = open_group(c, "envelope", len);
+    bench(&mut group, "batch", len, || Channel::envelope(data, 0.05));
+    bench(&mut group, "series", len, || {
+        Channel::envelope_columnar(columns.close(), PERIOD, 0.05).unwrap()
+    });
+    bench(&mut group, "incremental", len, || {
+        let mut ema = MovingAverage::simple(&data[..PERIOD]);
+        let mut result = vec![Channel::envelope_from(ema, 0.05)];
+        for elem in data.iter().skip(PERIOD) {
+            ema = MovingAverage::exponential_from(PERIOD, &ema, elem);
+            result.push(Channel::envelope_from(ema, 0.05));
+        }
+        result
+    });
+    group.finish();
+
+    let mut group = open_group(c, "bollinger", len);
+    bench(&mut group, "batch", len, || {
+        Channel::bollinger(data, 2f64, false).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        Channel::bollinger_columnar(columns.close(), PERIOD, 2f64).unwrap()
+    });
+    group.finish();
+
+    let mut group = open_group(c, "linear_regression", len);
+    bench(&mut group, "batch", len, || {
+        Channel::linear_regression(data, 2f64).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        Channel::linear_regression_columnar(columns.close(), PERIOD, 2f64).unwrap()
+    });
+    bench(&mut group, "incremental", len, || {
+        let mut regression = LinearRegression::new(&data[..PERIOD]).unwrap();
+        let mut result = vec![Channel::linear_regression_from(regression, 2f64)];
+        for i in PERIOD..len {
+            regression = LinearRegression::from_prev(&regression, &data[i - PERIOD], &data[i]);
+            result.push(Channel::linear_regression_from(regression, 2f64));
+        }
+        result
+    });
+    group.finish();
+}
+
+fn oscillators(c: &mut Criterion, data: &[Ohlcv], columns: &CandleColumns) {
+    let len = data.len();
+    let mut group = open_group(c, "stochastic", len);
+    bench(&mut group, "batch", len, || {
+        StochasticWindow::new(data, PERIOD, 3).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        StochasticWindow::columnar(columns, PERIOD, 3).unwrap()
+    });
+    bench(&mut group, "incremental", len, || {
+        let warmup = PERIOD + 2;
+        let mut window = StochasticWindow::new(&data[..warmup], PERIOD, 3).unwrap();
+        let mut result = vec![window.slow().inner()];
+        for elem in data.iter().skip(warmup) {
+            window = StochasticWindow::from_prev(&window, elem);
+            result.push(window.slow().inner());
+        }
+        result
+    });
+    group.finish();
+
+    let mut group = open_group(c, "williams_r", len);
+    bench(&mut group, "batch", len, || {
+        WilliamsR::new(data, PERIOD).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        WilliamsR::columnar(columns, PERIOD).unwrap()
+    });
+    group.finish();
+
+    let mut group = open_group(c, "commodity_channel_index", len);
+    bench(&mut group, "batch", len, || {
+        CommodityChannelIndex::new(data, PERIOD).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        CommodityChannelIndex::columnar(columns, PERIOD).unwrap()
+    });
+    group.finish();
+
+    let mut group = open_group(c, "ultimate_oscillator", len);
+    bench(&mut group, "batch", len, || {
+        UltimateOscillator::new(data, 7, 14, 28).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        UltimateOscillator::columnar(columns, 7, 14, 28).unwrap()
+    });
+    group.finish();
+
+    let mut group = open_group(c, "elder_ray", len);
+    bench(&mut group, "batch", len, || ElderRay::new(data).unwrap());
+    bench(&mut group, "series", len, || {
+        ElderRay::columnar(columns, PERIOD).unwrap()
+    });
+    group.finish();
+}
+
+fn volume(c: &mut Criterion, data: &[Ohlcv], columns: &CandleColumns) {
+    let len = data.len();
+    let mut group = open_group(c, "accumulation_distribution", len);
+    bench(&mut group, "batch", len, || {
+        AccumulationDistribution::new(data).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        AccumulationDistribution::columnar(columns).unwrap()
+    });
+    bench(&mut group, "incremental", len, || {
+        let mut adl = AccumulationDistribution::new(&data[..1]).unwrap();
+        let mut result = vec![adl.inner()];
+        for elem in data.iter().skip(1) {
+            adl = AccumulationDistribution::from_prev(&adl, elem);
+            result.push(adl.inner());
+        }
+        result
+    });
+    group.finish();
+
+    let mut group = open_group(c, "chaikin_oscillator", len);
+    bench(&mut group, "batch", len, || {
+        ChaikinOscillator::new(data).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        ChaikinOscillator::columnar(columns).unwrap()
+    });
+    bench(&mut group, "incremental", len, || {
+        let mut adl = AccumulationDistribution::new(&data[..10]).unwrap();
+        let mut oscillator = ChaikinOscillator::new(&data[..10]).unwrap();
+        let mut result = vec![oscillator.inner()];
+        for elem in data.iter().skip(10) {
+            adl = AccumulationDistribution::from_prev(&adl, elem);
+            oscillator = ChaikinOscillator::from_prev(&oscillator, &adl);
+            result.push(oscillator.inner());
+        }
+        result
+    });
+    group.finish();
+
+    let mut group = open_group(c, "chaikin_money_flow", len);
+    bench(&mut group, "batch", len, || {
+        ChaikinMoneyFlow::new(data).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        ChaikinMoneyFlow::columnar(columns, PERIOD).unwrap()
+    });
+    group.finish();
+
+    let mut group = open_group(c, "money_flow_index", len);
+    bench(&mut group, "batch", len, || {
+        MoneyFlowIndex::new(data).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        MoneyFlowIndex::columnar(columns, PERIOD).unwrap()
+    });
+    group.finish();
+
+    let mut group = open_group(c, "on_balance_volume", len);
+    bench(&mut group, "batch", len, || {
+        OnBalanceVolume::new(data).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        OnBalanceVolume::columnar(columns).unwrap()
+    });
+    bench(&mut group, "incremental", len, || {
+        let mut obv = OnBalanceVolume::new(&data[..1]).unwrap();
+        let mut result = vec![obv.inner()];
+        for pair in data.windows(2) {
+            obv = OnBalanceVolume::from_prev(&obv, &pair[0], &pair[1]);
+            result.push(obv.inner());
+        }
+        result
+    });
+    group.finish();
+
+    // force index is computed from 2 bars, so batch is the latest pair
+    let mut group = open_group(c, "force_index", len);
+    bench(&mut group, "batch", len, || {
+        ForceIndex::new(&data[len - 2], &data[len - 1])
+    });
+    bench(&mut group, "series", len, || {
+        ForceIndex::columnar(columns).unwrap()
+    });
+    bench(&mut group, "incremental", len, || {
+        data.windows(2)
+            .map(|pair| ForceIndex::new(&pair[0], &pair[1]).inner())
+            .collect::<Vec<f64>>()
+    });
+    group.finish();
+}
+
+fn trend(c: &mut Criterion, data: &[Ohlcv], _columns: &CandleColumns) {
+    let len = data.len();
+    let mut group = open_group(c, "average_true_range", len);
+    bench(&mut group, "batch", len, || {
+        AverageTrueRange::new(data, PERIOD).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        AverageTrueRange::series(data, PERIOD).unwrap()
+    });
+    bench(&mut group, "incremental", len, || {
+        let mut atr = AverageTrueRange::new(&data[..=PERIOD], PERIOD).unwrap();
+        let mut result = vec![atr.inner()];
+        for elem in data.iter().skip(PERIOD + 1) {
+            atr = AverageTrueRange::from_prev(PERIOD, &atr, elem);
+            result.push(atr.inner());
+        }
+        result
+    });
+    group.finish();
+
+    let mut group = open_group(c, "parabolic_sar", len);
+    bench(&mut group, "batch", len, || {
+        ParabolicSar::new(data, 0.02, 0.2).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        ParabolicSar::series(data, 0.02, 0.2).unwrap()
+    });
+    bench(&mut group, "incremental", len, || {
+        let mut sar = ParabolicSar::new(&data[..2], 0.02, 0.2).unwrap();
+        let mut result = vec![sar.sar()];
+        for elem in data.iter().skip(2) {
+            sar = ParabolicSar::from_prev(&sar, elem);
+            result.push(sar.sar());
+        }
+        result
+    });
+    group.finish();
+
+    let mut group = open_group(c, "super_trend", len);
+    bench(&mut group, "batch", len, || {
+        SuperTrend::new(data, 10, 3f64).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        SuperTrend::series(data, 10, 3f64).unwrap()
+    });
+    bench(&mut group, "incremental", len, || {
+        let mut super_trend = SuperTrend::new(&data[..11], 10, 3f64).unwrap();
+        let mut result = vec![super_trend.stop()];
+        for elem in data.iter().skip(11) {
+            super_trend = SuperTrend::from_prev(&super_trend, elem);
+            result.push(super_trend.stop());
+        }
+        result
+    });
+    group.finish();
+
+    let mut group = open_group(c, "ichimoku", len);
+    bench(&mut group, "batch", len, || {
+        Ichimoku::new(data, 9, 26, 52, 26).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        Ichimoku::series(data, 9, 26, 52, 26).unwrap()
+    });
+    group.finish();
+}
+
+fn volatility(c: &mut Criterion, data: &[Ohlcv], columns: &CandleColumns) {
+    let len = data.len();
+    let year = Time::from_days(365);
+    let methods: [(&str, Generic, Columnar); 5] = [
+        (
+            "close_to_close",
+            Volatility::close_to_close,
+            Volatility::close_to_close_columnar,
+        ),
+        (
+            "parkinson",
+            Volatility::parkinson,
+            Volatility::parkinson_columnar,
+        ),
+        (
+            "garman_klass",
+            Volatility::garman_klass,
+            Volatility::garman_klass_columnar,
+        ),
+        (
+            "rogers_satchell",
+            Volatility::rogers_satchell,
+            Volatility::rogers_satchell_columnar,
+        ),
+        (
+            "yang_zhang",
+            Volatility::yang_zhang,
+            Volatility::yang_zhang_columnar,
+        ),
+    ];
+    for (name, generic, columnar) in methods {
+        let mut group = open_group(c, &format!("volatility_{name}"), len);
+        bench(&mut group, "batch", len, || generic(data, year).unwrap());
+        bench(&mut group, "series", len, || {
+            columnar(columns, PERIOD, year).unwrap()
+        });
+        group.finish();
+    }
+}
+
+fn events(c: &mut Criterion, data: &[Ohlcv], columns: &CandleColumns) {
+    let len = data.len();
+    // hourly sessions so that 1k minute bars have more than a session
+    let (hour, offset) = (Time::from_hours(1), Time::from_minutes(0));
+    let mut group = open_group(c, "pivot", len);
+    bench(&mut group, "batch", len, || {
+        Pivot::sessions(data, hour, offset, Pivot::floor).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        Pivot::sessions_columnar(columns, hour, offset, Pivot::floor).unwrap()
+    });
+    group.finish();
+
+    let mut group = open_group(c, "swing", len);
+    bench(&mut group, "batch", len, || {
+        SwingPoint::detect(data, 2, 2).unwrap()
+    });
+    bench(&mut group, "series", len, || {
+        SwingPoint::detect_columnar(columns, 2, 2).unwrap()
+    });
+    group.finish();
+
+    let mut group = open_group(c, "zigzag", len);
+    let zigzag = ZigZag::Atr(PERIOD, 3f64);
+    bench(&mut group, "batch", len, || zigzag.pivots(data).unwrap());
+    bench(&mut group, "series", len, || {
+        zigzag.pivots_columnar(columns).unwrap()
+    });
+    group.finish();
+}
+
+criterion_group!(benches, indicators);
+criterion_main!(benches);
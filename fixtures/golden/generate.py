@@ -0,0 +1,581 @@
+#!/usr/bin/env python3
+"""Generate the input candles and the hand-written reference fixtures.
+
+The expected values in reference/ are computed by the straightforward
+reference implementations below, written from the published formulas with
+plain Python floats (no third party packages). They intentionally recompute
+every window from scratch instead of rolling sums, so they share no code or
+numerical shortcuts with the crate. They are NOT an external reference,
+they encode the same definitions as the crate: the indicators TA-Lib
+implements are checked against TA-Lib output in talib/(generate_talib.py),
+and reference/ is the only check of the indicators TA-Lib lacks.
+
+Seeding conventions follow TA-Lib where TA-Lib defines one: EMAs are seeded
+with the SMA of their first `period` values, Wilder's ATR with the SMA of the
+first `period` true ranges, the EMA(12) of MACD with the 12 bars before its
+first EMA(26), the MACD signal with the SMA of the first 9 MACD values, ADOSC
+with the first A/D value, OBV starts at the first volume, and the Parabolic
+SAR starts short if bar 1 has a minus DM. Indicators TA-Lib does not have (envelope, linear regression
+channel, Ichimoku, SuperTrend, pivot points, swing points, ZigZag, the
+historical volatility estimators, ...) follow the definitions documented in
+the crate's modules.
+
+Usage: python3 fixtures/golden/generate.py
+Writes candles.csv next to this script and one CSV per indicator to reference/.
+"""
+
+import math
+import os
+
+DIR = os.path.dirname(os.path.abspath(__file__))
+REFERENCE = os.path.join(DIR, "reference")
+DAY = 86_400_000
+START = 1_704_067_200_000  # 2024-01-01T00:00:00Z
+BARS = 300
+YEAR_BARS = 365.0
+NAN = float("nan")
+
+
+def candles():
+    """deterministic daily random walk, prices rounded to cents"""
+    state = 20240101
+    rows = []
+    close = 100.0
+
+    def rand():
+        nonlocal state
+        # 64-bit LCG (Knuth MMIX)
+        state = (state * 6364136223846793005 + 1442695040888963407) % (1 << 64)
+        return (state >> 11) / float(1 << 53)
+
+    for i in range(BARS):
+        open_ = round(close * (1 + (rand() - 0.5) * 0.01), 2)
+        if i % 37 == 5:
+            # unchanged close, exercises the flat branches of OBV/MFI
+            close = rows[-1][4]
+        else:
+            close = round(max(open_ * (1 + (rand() - 0.48) * 0.04), 1.0), 2)
+        high = round(max(open_, close) * (1 + rand() * 0.015), 2)
+        low = round(min(open_, close) * (1 - rand() * 0.015), 2)
+        volume = 1000 + int(rand() * 9000)
+        rows.append((START + i * DAY, open_, high, low, close, volume))
+    return rows
+
+
+def sma(values, period):
+    out = [NAN] * len(values)
+    for i in range(period - 1, len(values)):
+        out[i] = sum(values[i + 1 - period : i + 1]) / period
+    return out
+
+
+def ema(values, period):
+    """EMA seeded with the SMA of the first `period` values, NaN values are skipped at the start"""
+    out = [NAN] * len(values)
+    start = next(i for i, v in enumerate(values) if not math.isnan(v))
+    if len(values) - start < period:
+        return out
+    k = 2.0 / (period + 1)
+    seed = start + period - 1
+    out[seed] = sum(values[start : seed + 1]) / period
+    for i in range(seed + 1, len(values)):
+        out[i] = values[i] * k + out[i - 1] * (1 - k)
+    return out
+
+
+def true_range(high, low, prev_close):
+    return max(high, prev_close) - min(low, prev_close)
+
+
+def typical(h, l, c):
+    return (h + l + c) / 3.0
+
+
+def atr(highs, lows, closes, period):
+    n = len(closes)
+    out = [NAN] * n
+    trs = [NAN] + [true_range(highs[i], lows[i], closes[i - 1]) for i in range(1, n)]
+    out[period] = sum(trs[1 : period + 1]) / period
+    for i in range(period + 1, n):
+        out[i] = (out[i - 1] * (period - 1) + trs[i]) / period
+    return out
+
+
+def macd(closes):
+    """TA-Lib starts both EMAs at bar 25: EMA(12) is seeded with the SMA of bars 14..25"""
+    fast = [NAN] * 14 + ema(closes[14:], 12)
+    line = [a - b for a, b in zip(fast, ema(closes, 26))]
+    return line, ema(line, 9)
+
+
+def trix(closes, period):
+    e3 = ema(ema(ema(closes, period), period), period)
+    out = [NAN] * len(closes)
+    for i in range(1, len(closes)):
+        if not math.isnan(e3[i - 1]):
+            out[i] = (e3[i] - e3[i - 1]) / e3[i - 1] * 100.0
+    return out
+
+
+def momentum(closes, period):
+    return [NAN] * period + [closes[i] - closes[i - period] for i in range(period, len(closes))]
+
+
+def rate_of_change(closes, period):
+    return [NAN] * period + [
+        (closes[i] - closes[i - period]) / closes[i - period] * 100.0
+        for i in range(period, len(closes))
+    ]
+
+
+def envelope(closes, period, coefficient):
+    mid = ema(closes, period)
+    return [m * (1 + coefficient) for m in mid], mid, [m * (1 - coefficient) for m in mid]
+
+
+def bollinger(closes, period, dev_mul):
+    upper, mid, lower = [NAN] * len(closes), [NAN] * len(closes), [NAN] * len(closes)
+    for i in range(period - 1, len(closes)):
+        window = closes[i + 1 - period : i + 1]
+        mean = sum(window) / period
+        stdev = math.sqrt(sum((v - mean) ** 2 for v in window) / period)
+        upper[i], mid[i], lower[i] = mean + dev_mul * stdev, mean, mean - dev_mul * stdev
+    return upper, mid, lower
+
+
+def linear_regression(closes, period, dev_mul):
+    """OLS over x = 0..period-1, line value at the latest bar ± population stdev of residuals"""
+    upper, mid, lower = [NAN] * len(closes), [NAN] * len(closes), [NAN] * len(closes)
+    xs = list(range(period))
+    mean_x = sum(xs) / period
+    sxx = sum((x - mean_x) ** 2 for x in xs)
+    for i in range(period - 1, len(closes)):
+        ys = closes[i + 1 - period : i + 1]
+        mean_y = sum(ys) / period
+        slope = sum((x - mean_x) * (y - mean_y) for x, y in zip(xs, ys)) / sxx
+        intercept = mean_y - slope * mean_x
+        residuals = [y - (intercept + slope * x) for x, y in zip(xs, ys)]
+        stdev = math.sqrt(sum(r * r for r in residuals) / period)
+        end = intercept + slope * (period - 1)
+        upper[i], mid[i], lower[i] = end + dev_mul * stdev, end, end - dev_mul * stdev
+    return upper, mid, lower
+
+
+def stochastic(highs, lows, closes, period, smoothing):
+    fast = [NAN] * len(closes)
+    for i in range(period - 1, len(closes)):
+        high = max(highs[i + 1 - period : i + 1])
+        low = min(lows[i + 1 - period : i + 1])
+        fast[i] = (closes[i] - low) / (high - low) * 100.0
+    slow = [NAN] * len(closes)
+    for i in range(period + smoothing - 2, len(closes)):
+        slow[i] = sum(fast[i + 1 - smoothing : i + 1]) / smoothing
+    return fast, slow
+
+
+def williams_r(highs, lows, closes, period):
+    out = [NAN] * len(closes)
+    for i in range(period - 1, len(closes)):
+        high = max(highs[i + 1 - period : i + 1])
+        low = min(lows[i + 1 - period : i + 1])
+        out[i] = (high - closes[i]) / (high - low) * -100.0
+    return out
+
+
+def commodity_channel_index(highs, lows, closes, period):
+    tps = [typical(h, l, c) for h, l, c in zip(highs, lows, closes)]
+    out = [NAN] * len(closes)
+    for i in range(period - 1, len(closes)):
+        window = tps[i + 1 - period : i + 1]
+        mean = sum(window) / period
+        deviation = sum(abs(tp - mean) for tp in window) / period
+        out[i] = (tps[i] - mean) / (0.015 * deviation)
+    return out
+
+
+def ultimate_oscillator(highs, lows, closes, short, mid, long):
+    n = len(closes)
+    bp = [NAN] + [closes[i] - min(lows[i], closes[i - 1]) for i in range(1, n)]
+    tr = [NAN] + [true_range(highs[i], lows[i], closes[i - 1]) for i in range(1, n)]
+    out = [NAN] * n
+
+    def average(i, period):
+        return sum(bp[i + 1 - period : i + 1]) / sum(tr[i + 1 - period : i + 1])
+
+    for i in range(long, n):
+        out[i] = (4 * average(i, short) + 2 * average(i, mid) + average(i, long)) / 7 * 100.0
+    return out
+
+
+def elder_ray(highs, lows, closes, period):
+    e = ema(closes, period)
+    # (seller's force, buyer's force) = (bear power, bull power)
+    return [l - m for l, m in zip(lows, e)], [h - m for h, m in zip(highs, e)]
+
+
+def money_flow_volume(h, l, c, v):
+    if h == l:
+        return 0.0
+    return ((c - l) - (h - c)) / (h - l) * v
+
+
+def accumulation_distribution(highs, lows, closes, volumes):
+    out, total = [], 0.0
+    for h, l, c, v in zip(highs, lows, closes, volumes):
+        total += money_flow_volume(h, l, c, v)
+        out.append(total)
+    return out
+
+
+def chaikin_oscillator(highs, lows, closes, volumes):
+    """TA-Lib's ADOSC: both EMAs are seeded with the first A/D value, output from bar 9"""
+    adl = accumulation_distribution(highs, lows, closes, volumes)
+    out = [NAN] * len(closes)
+    fast = slow = adl[0]
+    for i in range(1, len(closes)):
+        fast = adl[i] * (2.0 / 4) + fast * (1 - 2.0 / 4)
+        slow = adl[i] * (2.0 / 11) + slow * (1 - 2.0 / 11)
+        if i >= 9:
+            out[i] = fast - slow
+    return out
+
+
+def chaikin_money_flow(highs, lows, closes, volumes, period):
+    mfv = [money_flow_volume(*row) for row in zip(highs, lows, closes, volumes)]
+    out = [NAN] * len(closes)
+    for i in range(period - 1, len(closes)):
+        out[i] = sum(mfv[i + 1 - period : i + 1]) / sum(volumes[i + 1 - period : i + 1])
+    return out
+
+
+def money_flow_index(highs, lows, closes, volumes, period):
+    tps = [typical(h, l, c) for h, l, c in zip(highs, lows, closes)]
+    out = [NAN] * len(closes)
+    for i in range(period, len(closes)):
+        positive = negative = 0.0
+        for j in range(i + 1 - period, i + 1):
+            flow = tps[j] * volumes[j]
+            if tps[j] > tps[j - 1]:
+                positive += flow
+            elif tps[j] < tps[j - 1]:
+                negative += flow
+        out[i] = positive / (positive + negative) * 100.0
+    return out
+
+
+def on_balance_volume(closes, volumes):
+    """starts at the volume of the first bar like TA-Lib"""
+    out = [float(volumes[0])]
+    for i in range(1, len(closes)):
+        sign = (closes[i] > closes[i - 1]) - (closes[i] < closes[i - 1])
+        out.append(out[-1] + sign * volumes[i])
+    return out
+
+
+def force_index(closes, volumes):
+    return [NAN] + [(closes[i] - closes[i - 1]) * volumes[i] for i in range(1, len(closes))]
+
+
+def parabolic_sar(highs, lows, closes, step, maximum):
+    """Wilder's Parabolic SAR as TA-Lib computes it
+
+    The initial trend is short if bar 1 has a minus DM(a lower low larger
+    than the higher high), else long. A reversal happens when the bar
+    touches the SAR, and the reversal SAR is kept outside the range of the
+    previous and current bars.
+    """
+    n = len(closes)
+    out = [NAN] * n
+    plus_dm, minus_dm = highs[1] - highs[0], lows[0] - lows[1]
+    up = not (minus_dm > 0 and plus_dm < minus_dm)
+    if up:
+        sar, ep = lows[0], highs[1]
+    else:
+        sar, ep = highs[0], lows[1]
+    af = step
+    for i in range(1, n):
+        # bar 1 is its own previous bar
+        prev = max(i - 1, 1)
+        if up:
+            if lows[i] <= sar:
+                up = False
+                sar = max(ep, highs[prev], highs[i])
+                out[i] = sar
+                af, ep = step, lows[i]
+                sar = max(sar + af * (ep - sar), highs[prev], highs[i])
+            else:
+                out[i] = sar
+                if highs[i] > ep:
+                    ep, af = highs[i], min(af + step, maximum)
+                sar = min(sar + af * (ep - sar), lows[prev], lows[i])
+        else:
+            if highs[i] >= sar:
+                up = True
+                sar = min(ep, lows[prev], lows[i])
+                out[i] = sar
+                af, ep = step, highs[i]
+                sar = min(sar + af * (ep - sar), lows[prev], lows[i])
+            else:
+                out[i] = sar
+                if lows[i] < ep:
+                    ep, af = lows[i], min(af + step, maximum)
+                sar = max(sar + af * (ep - sar), highs[prev], highs[i])
+    return out
+
+
+def super_trend(highs, lows, closes, period, multiplier):
+    """final bands tighten only with the trend and reset once the close crosses them"""
+    n = len(closes)
+    atrs = atr(highs, lows, closes, period)
+    out = [NAN] * n
+    upper = lower = None
+    up = None
+    for i in range(period, n):
+        mid = (highs[i] + lows[i]) / 2
+        basic_upper, basic_lower = mid + multiplier * atrs[i], mid - multiplier * atrs[i]
+        if upper is None:
+            upper, lower, up = basic_upper, basic_lower, closes[i] >= mid
+        else:
+            prev_close = closes[i - 1]
+            upper = basic_upper if basic_upper < upper or prev_close > upper else upper
+            lower = basic_lower if basic_lower > lower or prev_close < lower else lower
+            if not up and closes[i] > upper:
+                up = True
+            elif up and closes[i] < lower:
+                up = False
+        out[i] = lower if up else upper
+    return out
+
+
+def ichimoku(highs, lows, tenkan, kijun, senkou_b):
+    """lines at the bar they're computed from(undisplaced), NaN before the longest period"""
+    n = len(highs)
+    longest = max(tenkan, kijun, senkou_b)
+
+    def mid_price(i, period):
+        return (max(highs[i + 1 - period : i + 1]) + min(lows[i + 1 - period : i + 1])) / 2
+
+    lines = {"tenkan": [NAN] * n, "kijun": [NAN] * n, "senkou_a": [NAN] * n, "senkou_b": [NAN] * n}
+    for i in range(longest - 1, n):
+        lines["tenkan"][i] = mid_price(i, tenkan)
+        lines["kijun"][i] = mid_price(i, kijun)
+        lines["senkou_a"][i] = (lines["tenkan"][i] + lines["kijun"][i]) / 2
+        lines["senkou_b"][i] = mid_price(i, senkou_b)
+    return lines
+
+
+def volatility(rows, period, estimator):
+    """annualised(365 bars a year) volatility of the latest `period` bars"""
+    out = [NAN] * len(rows)
+    ln = math.log
+    for i in range(period - 1, len(rows)):
+        window = rows[i + 1 - period : i + 1]
+        if estimator == "close_to_close":
+            returns = [ln(b[4] / a[4]) for a, b in zip(window, window[1:])]
+            mean = sum(returns) / len(returns)
+            variance = sum((r - mean) ** 2 for r in returns) / (len(returns) - 1)
+        elif estimator == "parkinson":
+            variance = sum(ln(r[2] / r[3]) ** 2 for r in window) / (4 * ln(2) * period)
+        elif estimator == "garman_klass":
+            variance = sum(
+                0.5 * ln(r[2] / r[3]) ** 2 - (2 * ln(2) - 1) * ln(r[4] / r[1]) ** 2 for r in window
+            ) / period
+            variance = max(variance, 0.0)
+        elif estimator == "rogers_satchell":
+            variance = sum(rogers_satchell_term(r) for r in window) / period
+        else:
+            # Yang-Zhang over the period - 1 bars having a previous close
+            pairs = list(zip(window, window[1:]))
+            m = len(pairs)
+            overnight = [ln(b[1] / a[4]) for a, b in pairs]
+            open_close = [ln(b[4] / b[1]) for _, b in pairs]
+
+            def sample_variance(values):
+                mean = sum(values) / len(values)
+                return sum((v - mean) ** 2 for v in values) / (len(values) - 1)
+
+            k = 0.34 / (1.34 + (m + 1) / (m - 1))
+            rs = sum(rogers_satchell_term(b) for _, b in pairs) / m
+            variance = sample_variance(overnight) + k * sample_variance(open_close) + (1 - k) * rs
+        out[i] = math.sqrt(variance * YEAR_BARS)
+    return out
+
+
+def rogers_satchell_term(row):
+    _, o, h, l, c, _ = row
+    return math.log(h / c) * math.log(h / o) + math.log(l / c) * math.log(l / o)
+
+
+def pivots(rows, length, method):
+    """levels of every session from the prior session, sessions start at multiples of `length`"""
+    sessions = []
+    for row in rows:
+        start = row[0] - row[0] % length
+        if not sessions or sessions[-1][0] != start:
+            sessions.append((start, []))
+        sessions[-1][1].append(row)
+    out = []
+    for (_, prev), (start, _) in zip(sessions, sessions[1:]):
+        o, c = prev[0][1], prev[-1][4]
+        h, l = max(r[2] for r in prev), min(r[3] for r in prev)
+        p = (h + l + c) / 3
+        if method == "floor":
+            levels = [p, 2 * p - l, p + (h - l), h + 2 * (p - l), 2 * p - h, p - (h - l), l - 2 * (h - p)]
+        elif method == "fibonacci":
+            ratios = [0.382, 0.618, 1.0]
+            levels = [p] + [p + r * (h - l) for r in ratios] + [p - r * (h - l) for r in ratios]
+        elif method == "camarilla":
+            ratios = [1.1 / 12, 1.1 / 6, 1.1 / 4, 1.1 / 2]
+            levels = [p] + [c + r * (h - l) for r in ratios] + [c - r * (h - l) for r in ratios]
+        elif method == "woodie":
+            p = (h + l + 2 * c) / 4
+            levels = [p, 2 * p - l, p + (h - l), 2 * p - h, p - (h - l)]
+        else:
+            if c < o:
+                x = h + 2 * l + c
+            elif c > o:
+                x = 2 * h + l + c
+            else:
+                x = h + l + 2 * c
+            levels = [x / 4, x / 2 - l, x / 2 - h]
+        out.append([start] + levels)
+    return out
+
+
+def swings(rows, left, right):
+    """N-bar fractal: strictly beyond `left` bars before, not exceeded by `right` bars after"""
+    out = []
+    highs, lows = [r[2] for r in rows], [r[3] for r in rows]
+    for i in range(left, len(rows) - right):
+        if all(h < highs[i] for h in highs[i - left : i]) and all(
+            h <= highs[i] for h in highs[i + 1 : i + right + 1]
+        ):
+            out.append([1, rows[i][0], highs[i], rows[i + right][0]])
+        if all(l > lows[i] for l in lows[i - left : i]) and all(
+            l >= lows[i] for l in lows[i + 1 : i + right + 1]
+        ):
+            out.append([-1, rows[i][0], lows[i], rows[i + right][0]])
+    return out
+
+
+def zigzag(rows, threshold):
+    """alternating swing points, confirmed once the price reverses from the running extreme
+    by more than `threshold(i, extreme)`"""
+    highs, lows = [r[2] for r in rows], [r[3] for r in rows]
+    out = []
+    direction = 0  # 1 after a swing high, -1 after a swing low
+    high, low = (0, highs[0]), (0, lows[0])
+    for i in range(len(rows)):
+        if highs[i] > high[1]:
+            high = (i, highs[i])
+        if lows[i] < low[1]:
+            low = (i, lows[i])
+        t_high, t_low = threshold(i, high[1]), threshold(i, low[1])
+        confirm_high = direction != 1 and t_high is not None and high[1] - lows[i] > t_high
+        confirm_low = direction != -1 and t_low is not None and highs[i] - low[1] > t_low
+        if confirm_high and confirm_low:
+            # the earlier extreme comes first
+            kind = 1 if high[0] < low[0] else -1
+        elif confirm_high or confirm_low:
+            kind = 1 if confirm_high else -1
+        else:
+            continue
+        index, price = high if kind == 1 else low
+        out.append([kind, rows[index][0], price, rows[i][0]])
+        direction = kind
+        # track the opposite extreme from the confirmed swing point
+        if kind == 1:
+            j = min(range(index, i + 1), key=lambda j: (lows[j], j))
+            low, high = (j, lows[j]), (i, highs[i])
+        else:
+            j = min(range(index, i + 1), key=lambda j: (-highs[j], j))
+            high, low = (j, highs[j]), (i, lows[i])
+    return out
+
+
+def fmt(value):
+    if isinstance(value, int):
+        return str(value)
+    if math.isnan(value):
+        return ""
+    return repr(value)
+
+
+def write(name, header, rows, directory=REFERENCE):
+    os.makedirs(directory, exist_ok=True)
+    with open(os.path.join(directory, name + ".csv"), "w") as f:
+        f.write(",".join(header) + "\n")
+        for row in rows:
+            f.write(",".join(fmt(v) for v in row) + "\n")
+
+
+def per_bar(name, times, columns):
+    write(
+        name,
+        ["timestamp"] + [c for c, _ in columns],
+        [[t] + [values[i] for _, values in columns] for i, t in enumerate(times)],
+    )
+
+
+def main():
+    rows = candles()
+    write("candles", ["timestamp", "open", "high", "low", "close", "volume"], rows, DIR)
+    t = [r[0] for r in rows]
+    o, h, l, c, v = ([r[k] for r in rows] for k in range(1, 6))
+
+    per_bar("sma_20", t, [("sma", sma(c, 20))])
+    per_bar("ema_20", t, [("ema", ema(c, 20))])
+    line, signal = macd(c)
+    per_bar("macd_12_26_9", t, [("macd", line), ("signal", signal)])
+    per_bar("trix_15", t, [("trix", trix(c, 15))])
+    per_bar("momentum_10", t, [("momentum", momentum(c, 10))])
+    per_bar("rate_of_change_10", t, [("roc", rate_of_change(c, 10))])
+    for name, band in [
+        ("envelope_20_0.025", envelope(c, 20, 0.025)),
+        ("bollinger_20_2", bollinger(c, 20, 2.0)),
+        ("linear_regression_20_2", linear_regression(c, 20, 2.0)),
+    ]:
+        per_bar(name, t, list(zip(["upper", "mid", "lower"], band)))
+    fast, slow = stochastic(h, l, c, 14, 3)
+    per_bar("stochastic_14_3", t, [("fast", fast), ("slow", slow)])
+    per_bar("williams_r_14", t, [("williams_r", williams_r(h, l, c, 14))])
+    per_bar("commodity_channel_index_20", t, [("cci", commodity_channel_index(h, l, c, 20))])
+    per_bar("ultimate_oscillator_7_14_28", t, [("uo", ultimate_oscillator(h, l, c, 7, 14, 28))])
+    ask, bid = elder_ray(h, l, c, 13)
+    per_bar("elder_ray_13", t, [("ask_force", ask), ("bid_force", bid)])
+    per_bar("accumulation_distribution", t, [("adl", accumulation_distribution(h, l, c, v))])
+    per_bar("chaikin_oscillator", t, [("oscillator", chaikin_oscillator(h, l, c, v))])
+    per_bar("chaikin_money_flow_20", t, [("cmf", chaikin_money_flow(h, l, c, v, 20))])
+    per_bar("money_flow_index_14", t, [("mfi", money_flow_index(h, l, c, v, 14))])
+    per_bar("on_balance_volume", t, [("obv", on_balance_volume(c, v))])
+    per_bar("force_index", t, [("force_index", force_index(c, v))])
+    per_bar("average_true_range_14", t, [("atr", atr(h, l, c, 14))])
+    per_bar("parabolic_sar_0.02_0.2", t, [("sar", parabolic_sar(h, l, c, 0.02, 0.2))])
+    per_bar("super_trend_10_3", t, [("stop", super_trend(h, l, c, 10, 3.0))])
+    per_bar("ichimoku_9_26_52", t, list(ichimoku(h, l, 9, 26, 52).items()))
+    for estimator in ["close_to_close", "parkinson", "garman_klass", "rogers_satchell", "yang_zhang"]:
+        per_bar("volatility_%s_20" % estimator, t, [("volatility", volatility(rows, 20, estimator))])
+
+    levels = {
+        "floor": ["r1", "r2", "r3", "s1", "s2", "s3"],
+        "fibonacci": ["r1", "r2", "r3", "s1", "s2", "s3"],
+        "camarilla": ["r1", "r2", "r3", "r4", "s1", "s2", "s3", "s4"],
+        "woodie": ["r1", "r2", "s1", "s2"],
+        "demark": ["r1", "s1"],
+    }
+    for method, names in levels.items():
+        write("pivot_%s_7d" % method, ["timestamp", "pivot"] + names, pivots(rows, 7 * DAY, method))
+
+    swing_header = ["kind", "timestamp", "price", "confirmed_at"]
+    write("swing_2_2", swing_header, swings(rows, 2, 2))
+    write("zigzag_percent_0.05", swing_header, zigzag(rows, lambda i, extreme: abs(extreme) * 0.05))
+    atrs = atr(h, l, c, 14)
+    write(
+        "zigzag_atr_14_3",
+        swing_header,
+        zigzag(rows, lambda i, _: None if math.isnan(atrs[i]) else atrs[i] * 3.0),
+    )
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,95 @@
+#!/usr/bin/env python3
+"""Generate the TA-Lib fixtures of trading-toolkit indicators.
+
+Every indicator TA-Lib implements is computed by TA-Lib itself over
+candles.csv, so these fixtures are an external reference for the crate.
+The output is written as TA-Lib returns it, without adjusting its
+conventions (seeding, first output bar, initial values) to the crate's:
+a mismatch is meant to fail `cargo test golden -- --ignored`. The test only
+skips the bars where TA-Lib has no value yet and the crate does.
+
+Requires numpy and the TA-Lib Python wrapper (`pip install numpy TA-Lib`,
+which needs the TA-Lib C library).
+
+Usage: python3 fixtures/golden/generate_talib.py
+Writes one CSV per indicator to talib/, run generate.py first for candles.csv.
+"""
+
+import csv
+import math
+import os
+
+import numpy as np
+import talib
+
+DIR = os.path.dirname(os.path.abspath(__file__))
+TALIB = os.path.join(DIR, "talib")
+
+
+def candles():
+    with open(os.path.join(DIR, "candles.csv")) as f:
+        rows = list(csv.DictReader(f))
+    times = [int(row["timestamp"]) for row in rows]
+    columns = {
+        key: np.array([float(row[key]) for row in rows], dtype=np.float64)
+        for key in ["open", "high", "low", "close", "volume"]
+    }
+    return times, columns
+
+
+def fmt(value):
+    value = float(value)
+    if math.isnan(value):
+        return ""
+    return repr(value)
+
+
+def per_bar(name, times, columns):
+    os.makedirs(TALIB, exist_ok=True)
+    with open(os.path.join(TALIB, name + ".csv"), "w") as f:
+        f.write(",".join(["timestamp"] + [c for c, _ in columns]) + "\n")
+        for i, t in enumerate(times):
+            f.write(",".join([str(t)] + [fmt(values[i]) for _, values in columns]) + "\n")
+
+
+def main():
+    t, c = candles()
+    h, l, close, v = c["high"], c["low"], c["close"], c["volume"]
+
+    per_bar("sma_20", t, [("sma", talib.SMA(close, timeperiod=20))])
+    per_bar("ema_20", t, [("ema", talib.EMA(close, timeperiod=20))])
+    line, signal, _ = talib.MACD(close, fastperiod=12, slowperiod=26, signalperiod=9)
+    per_bar("macd_12_26_9", t, [("macd", line), ("signal", signal)])
+    per_bar("trix_15", t, [("trix", talib.TRIX(close, timeperiod=15))])
+    per_bar("momentum_10", t, [("momentum", talib.MOM(close, timeperiod=10))])
+    per_bar("rate_of_change_10", t, [("roc", talib.ROC(close, timeperiod=10))])
+    upper, mid, lower = talib.BBANDS(close, timeperiod=20, nbdevup=2.0, nbdevdn=2.0, matype=0)
+    per_bar("bollinger_20_2", t, [("upper", upper), ("mid", mid), ("lower", lower)])
+    # %K of the latest 14 bars and its SMA(3), i.e. the fast stochastic
+    fast, slow = talib.STOCHF(h, l, close, fastk_period=14, fastd_period=3, fastd_matype=0)
+    per_bar("stochastic_14_3", t, [("fast", fast), ("slow", slow)])
+    per_bar("williams_r_14", t, [("williams_r", talib.WILLR(h, l, close, timeperiod=14))])
+    per_bar("commodity_channel_index_20", t, [("cci", talib.CCI(h, l, close, timeperiod=20))])
+    per_bar(
+        "ultimate_oscillator_7_14_28",
+        t,
+        [("uo", talib.ULTOSC(h, l, close, timeperiod1=7, timeperiod2=14, timeperiod3=28))],
+    )
+    per_bar("accumulation_distribution", t, [("adl", talib.AD(h, l, close, v))])
+    per_bar(
+        "chaikin_oscillator",
+        t,
+        [("oscillator", talib.ADOSC(h, l, close, v, fastperiod=3, slowperiod=10))],
+    )
+    per_bar("money_flow_index_14", t, [("mfi", talib.MFI(h, l, close, v, timeperiod=14))])
+    per_bar("on_balance_volume", t, [("obv", talib.OBV(close, v))])
+    per_bar("average_true_range_14", t, [("atr", talib.ATR(h, l, close, timeperiod=14))])
+    per_bar(
+        "parabolic_sar_0.02_0.2",
+        t,
+        [("sar", talib.SAR(h, l, acceleration=0.02, maximum=0.2))],
+    )
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,96 @@
+use crate::trading::Side;
+use crate::types::data::Candle;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Commission {
+    /// fixed amount per fill
+    Fixed(f64),
+    /// ratio of the traded value, e.g. 0.001 for 0.1%
+    Percent(f64),
+    /// amount per unit of quantity
+    PerShare(f64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Slippage {
+    /// fixed price amount against the order
+    Fixed(f64),
+    /// ratio of the price against the order
+    Percent(f64),
+    /// ratio of the price scaled by the order's share of the bar volume,
+    /// e.g. 0.1 moves the price 0.1% when the order is 1% of the volume
+    Volume(f64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Spread {
+    /// fixed bid-ask spread
+    Fixed(f64),
+    /// ratio of the bar range(high - low) approximating the bid-ask spread
+    Range(f64),
+}
+
+/// trading costs applied to every fill
+/// buys pay half the spread and the slippage above the price, sells below
+/// the backtest caps limit(and triggered stop-limit) fills at the limit price
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Costs {
+    pub commission: Option<Commission>,
+    /// minimum commission per fill, ignored without a commission model
+    pub min_commission: f64,
+    pub slippage: Option<Slippage>,
+    pub spread: Option<Spread>,
+}
+
+impl Commission {
+    pub fn of(&self, quantity: f64, price: f64) -> f64 {
+        match self {
+            Self::Fixed(amount) => *amount,
+            Self::Percent(ratio) => quantity * price * ratio,
+            Self::PerShare(amount) => quantity * amount,
+        }
+    }
+}
+
+impl Costs {
+    /// fill price after spread and slippage
+    pub fn fill_price<T>(&self, side: Side, quantity: f64, price: f64, bar: &T) -> f64
+    where
+        T: Candle,
+    {
+        let half_spread = match self.spread {
+            Some(Spread::Fixed(spread)) => spread / 2f64,
+            Some(Spread::Range(ratio)) => ratio * (bar.high_price() - bar.low_price()) / 2f64,
+            None => 0f64,
+        };
+        let slippage = match self.slippage {
+            Some(Slippage::Fixed(amount)) => amount,
+            Some(Slippage::Percent(ratio)) => price * ratio,
+            Some(Slippage::Volume(impact)) => {
+                let volume = bar.tot_exec_volume() as f64;
+                let participation = if volume > 0f64 {
+                    quantity / volume
+                } else {
+                    1f64
+                };
+                price * impact * participation
+            }
+            None => 0f64,
+        };
+        match side {
+            Side::Buy => price + half_spread + slippage,
+            Side::Sell => price - half_spread - slippage,
+        }
+    }
+
+    /// commission of a fill, the minimum only applies with a commission model
+    pub fn commission(&self, quantity: f64, price: f64) -> f64 {
+        self.commission
+            .map(|commission| commission.of(quantity, price).max(self.min_commission))
+            .unwrap_or(0f64)
+    }
+}
@@ -0,0 +1,157 @@
+use super::{Context, Costs, Strategy};
+use crate::trading::{Fill, Order, OrderKind, Position, Side, TimeInForce, Trade};
+use crate::types::{data::Candle, data::Point, error::ToolkitError};
+
+/// deterministic bar-by-bar simulation
+/// orders issued at the close of a bar are filled against the OHLC of the following bars,
+/// kept open according to their time in force
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Backtest {
+    initial_cash: f64,
+    costs: Costs,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BacktestResult {
+    pub fills: Vec<Fill>,
+    pub trades: Vec<Trade>,
+    /// equity at the close of each bar
+    pub equity: Vec<Point>,
+}
+
+impl Backtest {
+    /// backtest without trading costs
+    pub fn new(initial_cash: f64) -> Self {
+        Self {
+            initial_cash,
+            costs: Costs::default(),
+        }
+    }
+
+    /// apply the trading costs to every fill
+    pub fn with_costs(mut self, costs: Costs) -> Self {
+        self.costs = costs;
+        self
+    }
+
+    pub fn run<T, S>(&self, data: &[T], strategy: &mut S) -> Result<BacktestResult, ToolkitError>
+    where
+        T: Candle + Clone,
+        S: Strategy<T>,
+    {
+        if data.is_empty() {
+            return Err(ToolkitError::EmptyData);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+
+        let mut cash = self.initial_cash;
+        let mut position = Position::new();
+        let mut pending: Vec<Order> = vec![];
+        let mut fills = vec![];
+        let mut trades = vec![];
+        let mut equity = vec![];
+        for i in 0..data.len() {
+            let bar = &data[i];
+            let mut open_orders = vec![];
+            for mut order in pending.drain(..) {
+                if let TimeInForce::GoodTillTime(until) = order.time_in_force
+                    && bar.epoch_time() > until
+                {
+                    continue;
+                }
+                match Self::fill_price(&mut order, bar) {
+                    Some(price) => {
+                        let price = self
+                            .costs
+                            .fill_price(order.side, order.quantity, price, bar);
+                        // 지정가 주문은 spread, slippage를 더해도 지정가보다 불리하게 체결되지 않음
+                        let price = match (order.kind, order.side) {
+                            (OrderKind::Limit(limit), Side::Buy) => price.min(limit),
+                            (OrderKind::Limit(limit), Side::Sell) => price.max(limit),
+                            _ => price,
+                        };
+                        let fill = Fill {
+                            epoch_time: bar.epoch_time(),
+                            side: order.side,
+                            quantity: order.quantity,
+                            price,
+                            commission: self.costs.commission(order.quantity, price),
+                        };
+                        cash -= order.signed_quantity() * price + fill.commission;
+                        trades.extend(position.apply(&fill));
+                        fills.push(fill);
+                    }
+                    None => {
+                        if matches!(
+                            order.time_in_force,
+                            TimeInForce::GoodTillCancel | TimeInForce::GoodTillTime(_)
+                        ) {
+                            open_orders.push(order);
+                        }
+                    }
+                }
+            }
+
+            let bar_equity = cash + position.market_value(bar.close_price());
+            equity.push(Point::new(bar_equity, bar.epoch_time()));
+            let context = Context {
+                history: &data[..=i],
+                position: position.quantity(),
+                cash,
+                equity: bar_equity,
+                open_orders: &open_orders,
+            };
+            let orders = strategy.on_bar(&context);
+            if orders
+                .iter()
+                .any(|order| !(order.quantity > 0f64 && order.quantity.is_finite()))
+            {
+                return Err(ToolkitError::InvalidData);
+            }
+            pending = open_orders;
+            pending.extend(orders);
+        }
+
+        Ok(BacktestResult {
+            fills,
+            trades,
+            equity,
+        })
+    }
+
+    /// price the order is filled at on the bar, None if it's not filled
+    /// a gap through the limit/stop price is filled at the open
+    /// a triggered stop-limit order becomes a limit order if it's not filled at the stop price
+    fn fill_price<T>(order: &mut Order, bar: &T) -> Option<f64>
+    where
+        T: Candle,
+    {
+        let open = bar.open_price();
+        // IOC 주문은 시가로만 체결
+        let (high, low) = match order.time_in_force {
+            TimeInForce::ImmediateOrCancel => (open, open),
+            _ => (bar.high_price(), bar.low_price()),
+        };
+        match (order.kind, order.side) {
+            (OrderKind::Market, _) => Some(open),
+            (OrderKind::Limit(price), Side::Buy) => (low <= price).then(|| open.min(price)),
+            (OrderKind::Limit(price), Side::Sell) => (high >= price).then(|| open.max(price)),
+            (OrderKind::Stop(price), Side::Buy) => (high >= price).then(|| open.max(price)),
+            (OrderKind::Stop(price), Side::Sell) => (low <= price).then(|| open.min(price)),
+            (OrderKind::StopLimit { stop, limit }, side) => {
+                let triggered = match side {
+                    Side::Buy => (high >= stop).then(|| open.max(stop)),
+                    Side::Sell => (low <= stop).then(|| open.min(stop)),
+                }?;
+                order.kind = OrderKind::Limit(limit);
+                match side {
+                    Side::Buy => (triggered <= limit).then_some(triggered),
+                    Side::Sell => (triggered >= limit).then_some(triggered),
+                }
+            }
+        }
+    }
+}
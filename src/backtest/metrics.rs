@@ -0,0 +1,229 @@
+use super::BacktestResult;
+use crate::stats::RollingStats;
+use crate::trading::{Side, Trade};
+use crate::types::{
+    data::{BaseData, Point},
+    error::ToolkitError,
+    time::Time,
+};
+
+/// performance and risk metrics of a backtest
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Metrics {
+    pub equity: EquityMetrics,
+    pub trades: TradeMetrics,
+    /// ratio of bars holding a position
+    pub exposure: f64,
+}
+
+/// return and drawdown metrics of an equity curve
+/// returns are per bar, annualised with the number of bars in `year` derived from the bar spacing
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EquityMetrics {
+    pub total_return: f64,
+    /// compound annual growth rate
+    pub annualised_return: f64,
+    /// annualised standard deviation of the returns
+    pub volatility: f64,
+    pub sharpe: f64,
+    pub sortino: f64,
+    /// annualised return / max drawdown
+    pub calmar: f64,
+    /// largest peak-to-trough decline as a ratio of the peak
+    pub max_drawdown: f64,
+    /// longest time from a peak to its recovery(or the last bar if not recovered)
+    pub max_drawdown_duration: Time,
+}
+
+/// metrics of closed trades
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TradeMetrics {
+    /// ratio of trades with positive pnl
+    pub win_rate: f64,
+    /// gross profit / gross loss, infinite if there's no losing trade
+    pub profit_factor: f64,
+    /// average pnl / average loss, i.e. the average trade in units of the average loss(R-multiple)
+    /// infinite if there's no losing trade
+    pub expectancy: f64,
+    /// average pnl per trade
+    pub average_trade: f64,
+}
+
+impl Metrics {
+    /// `risk_free` is the annual risk-free rate, e.g. 0.03 for 3%
+    pub fn new(result: &BacktestResult, year: Time, risk_free: f64) -> Result<Self, ToolkitError> {
+        let equity = EquityMetrics::new(&result.equity, year, risk_free)?;
+        let mut epoch_times: Vec<u128> =
+            result.equity.iter().map(|point| point.epoch_time).collect();
+        epoch_times.sort();
+        Ok(Self {
+            equity,
+            trades: TradeMetrics::new(&result.trades),
+            exposure: Self::exposure(result, &epoch_times),
+        })
+    }
+
+    /// replay the fills to count the bars closed with an open position
+    fn exposure(result: &BacktestResult, epoch_times: &[u128]) -> f64 {
+        let mut fills = result.fills.clone();
+        fills.sort_by_key(|k| k.epoch_time);
+        let mut fills = fills.iter().peekable();
+        let mut position = 0f64;
+        let mut exposed = 0usize;
+        for epoch_time in epoch_times.iter() {
+            while let Some(fill) = fills.next_if(|fill| fill.epoch_time <= *epoch_time) {
+                position += match fill.side {
+                    Side::Buy => fill.quantity,
+                    Side::Sell => -fill.quantity,
+                };
+            }
+            // 부동소수점 오차로 남은 잔량은 무시
+            if position.abs() > f64::EPSILON {
+                exposed += 1;
+            }
+        }
+        exposed as f64 / epoch_times.len() as f64
+    }
+}
+
+impl EquityMetrics {
+    /// equity curve of any timestamped values, e.g. `BacktestResult::equity` or account balances
+    /// `risk_free` is the annual risk-free rate, e.g. 0.03 for 3%
+    pub fn new<T>(equity: &[T], year: Time, risk_free: f64) -> Result<Self, ToolkitError>
+    where
+        T: BaseData,
+    {
+        let mut equity: Vec<Point> = equity
+            .iter()
+            .map(|elem| Point::new(elem.value(), elem.epoch_time()))
+            .collect();
+        equity.sort_by_key(|k| k.epoch_time);
+        if equity.len() < 2 {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        if equity.iter().any(|point| point.value <= 0f64) {
+            return Err(ToolkitError::InvalidData);
+        }
+        let epoch_times: Vec<u128> = equity.iter().map(|point| point.epoch_time).collect();
+        let bars_per_year = year.inner() as f64 / Time::interval_of(&epoch_times)?.inner() as f64;
+
+        let returns: Vec<f64> = equity
+            .windows(2)
+            .map(|pair| pair[1].value / pair[0].value - 1f64)
+            .collect();
+        let stats = returns
+            .iter()
+            .fold(RollingStats::default(), |stats, value| stats.add(*value));
+        let risk_free = risk_free / bars_per_year;
+        let excess = stats.mean() - risk_free;
+
+        // it's safe since the vector's length > 1
+        let total_return = equity.last().unwrap().value / equity[0].value - 1f64;
+        let annualised_return =
+            (1f64 + total_return).powf(bars_per_year / returns.len() as f64) - 1f64;
+        let stdev = stats.sample_stdev();
+        let sharpe = if stdev == 0f64 {
+            0f64
+        } else {
+            excess / stdev * bars_per_year.sqrt()
+        };
+        let downside = (returns
+            .iter()
+            .map(|value| (value - risk_free).min(0f64).powi(2))
+            .sum::<f64>()
+            / returns.len() as f64)
+            .sqrt();
+        let sortino = if downside == 0f64 {
+            0f64
+        } else {
+            excess / downside * bars_per_year.sqrt()
+        };
+        let (max_drawdown, max_drawdown_duration) = Self::drawdown(&equity);
+        let calmar = if max_drawdown == 0f64 {
+            0f64
+        } else {
+            annualised_return / max_drawdown
+        };
+
+        Ok(Self {
+            total_return,
+            annualised_return,
+            volatility: stdev * bars_per_year.sqrt(),
+            sharpe,
+            sortino,
+            calmar,
+            max_drawdown,
+            max_drawdown_duration,
+        })
+    }
+
+    fn drawdown(equity: &[Point]) -> (f64, Time) {
+        let mut peak = equity[0];
+        let mut max_drawdown = 0f64;
+        let mut max_duration = 0u128;
+        let mut underwater = false;
+        for point in equity.iter() {
+            // 회복한 bar까지 기간에 포함
+            if underwater || point.value < peak.value {
+                max_duration = max_duration.max(point.epoch_time - peak.epoch_time);
+            }
+            if point.value >= peak.value {
+                peak = *point;
+                underwater = false;
+            } else {
+                underwater = true;
+                max_drawdown = max_drawdown.max(1f64 - point.value / peak.value);
+            }
+        }
+        (max_drawdown, Time::from(max_duration))
+    }
+}
+
+impl TradeMetrics {
+    pub fn new(trades: &[Trade]) -> Self {
+        let pnls: Vec<f64> = trades.iter().map(|trade| trade.pnl()).collect();
+        if pnls.is_empty() {
+            return Self {
+                win_rate: 0f64,
+                profit_factor: 0f64,
+                expectancy: 0f64,
+                average_trade: 0f64,
+            };
+        }
+        let wins: Vec<f64> = pnls.iter().copied().filter(|pnl| *pnl > 0f64).collect();
+        let losses: Vec<f64> = pnls
+            .iter()
+            .map(|pnl| -pnl)
+            .filter(|loss| *loss > 0f64)
+            .collect();
+        let gross_profit: f64 = wins.iter().sum();
+        let gross_loss: f64 = losses.iter().sum();
+        let count = pnls.len() as f64;
+        let average_trade = pnls.iter().sum::<f64>() / count;
+        let expectancy = if losses.is_empty() {
+            if average_trade > 0f64 {
+                f64::INFINITY
+            } else {
+                0f64
+            }
+        } else {
+            average_trade / (gross_loss / losses.len() as f64)
+        };
+        let profit_factor = if gross_loss > 0f64 {
+            gross_profit / gross_loss
+        } else if gross_profit > 0f64 {
+            f64::INFINITY
+        } else {
+            0f64
+        };
+        Self {
+            win_rate: wins.len() as f64 / count,
+            profit_factor,
+            expectancy,
+            average_trade,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+mod cost;
+mod engine;
+mod metrics;
+mod strategy;
+mod test;
+
+pub use cost::{Commission, Costs, Slippage, Spread};
+pub use engine::{Backtest, BacktestResult};
+pub use metrics::{EquityMetrics, Metrics, TradeMetrics};
+pub use strategy::{Context, Strategy};
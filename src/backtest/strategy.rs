@@ -0,0 +1,33 @@
+use crate::trading::Order;
+use crate::types::data::Candle;
+
+/// state of the simulation given to a strategy at the close of each bar
+pub struct Context<'a, T> {
+    /// bars up to the current bar(the last one), sorted by epoch time
+    pub history: &'a [T],
+    /// signed quantity, negative for short
+    pub position: f64,
+    pub cash: f64,
+    /// cash + position marked at the current close
+    pub equity: f64,
+    /// unfilled orders kept by their time in force
+    pub open_orders: &'a [Order],
+}
+
+impl<T> Context<'_, T>
+where
+    T: Candle,
+{
+    pub fn bar(&self) -> &T {
+        self.history.last().unwrap() // it's safe since the context is created for an existing bar
+    }
+}
+
+/// Strategy
+/// receives every bar in chronological order and issues orders from the next bar
+pub trait Strategy<T>
+where
+    T: Candle,
+{
+    fn on_bar(&mut self, context: &Context<T>) -> Vec<Order>;
+}
@@ -0,0 +1,322 @@
+#[cfg(test)]
+mod tests {
+    use crate::backtest::{
+        Backtest, BacktestResult, Commission, Context, Costs, EquityMetrics, Metrics, Slippage,
+        Spread, Strategy, TradeMetrics,
+    };
+    use crate::indicator::MovingAverage;
+    use crate::test_util::day;
+    use crate::trading::{Fill, Order, OrderKind, Side, TimeInForce, Trade};
+    use crate::types::{
+        data::{Candle, Ohlcv, Point},
+        time::Time,
+    };
+
+    static MAX_ERR: f64 = 0.0000000001f64;
+
+    fn sample_candles() -> Vec<Ohlcv> {
+        vec![
+            Ohlcv::new(100.0, 101.0, 99.0, 100.0, 1000, day(0)),
+            Ohlcv::new(100.0, 102.0, 99.0, 101.0, 1000, day(1)),
+            Ohlcv::new(101.0, 103.0, 100.0, 102.0, 1000, day(2)),
+            Ohlcv::new(102.0, 106.0, 101.0, 105.0, 1000, day(3)),
+            Ohlcv::new(106.0, 108.0, 104.0, 107.0, 1000, day(4)),
+            Ohlcv::new(107.0, 107.0, 100.0, 101.0, 1000, day(5)),
+            Ohlcv::new(100.0, 101.0, 95.0, 96.0, 1000, day(6)),
+            Ohlcv::new(96.0, 99.0, 94.0, 98.0, 1000, day(7)),
+        ]
+    }
+
+    /// close > SMA(3)이면 매수, close < SMA(3)이면 청산
+    struct SmaStrategy;
+    impl Strategy<Ohlcv> for SmaStrategy {
+        fn on_bar(&mut self, context: &Context<Ohlcv>) -> Vec<Order> {
+            if context.history.len() < 3 {
+                return vec![];
+            }
+            let window = &context.history[context.history.len() - 3..];
+            let sma = MovingAverage::simple(window).inner();
+            let close = context.bar().close_price();
+            if close > sma && context.position == 0f64 {
+                vec![Order::market(Side::Buy, 10f64)]
+            } else if close < sma && context.position > 0f64 {
+                vec![Order::market(Side::Sell, context.position)]
+            } else {
+                vec![]
+            }
+        }
+    }
+
+    #[test]
+    fn test_backtest() {
+        let data = sample_candles();
+        let result = Backtest::new(10000f64)
+            .run(&data, &mut SmaStrategy)
+            .unwrap();
+
+        // bar 2 종가(102) > SMA(101) → bar 3 시가(102)에 매수
+        // bar 5 종가(101) < SMA(104.33) → bar 6 시가(100)에 매도
+        assert_eq!(2, result.fills.len());
+        assert_eq!(day(3), result.fills[0].epoch_time);
+        assert_eq!(102f64, result.fills[0].price);
+        assert_eq!(day(6), result.fills[1].epoch_time);
+        assert_eq!(100f64, result.fills[1].price);
+
+        assert_eq!(1, result.trades.len());
+        assert_eq!(Side::Buy, result.trades[0].side);
+        assert!((result.trades[0].pnl() - (-20f64)).abs() < MAX_ERR);
+
+        assert_eq!(data.len(), result.equity.len());
+        assert_eq!(10000f64, result.equity[2].value);
+        // bar 4 종가 107에 10주 평가
+        assert!((result.equity[4].value - 10050f64).abs() < MAX_ERR);
+        assert!((result.equity.last().unwrap().value - 9980f64).abs() < MAX_ERR);
+
+        // 같은 입력은 항상 같은 결과
+        let mut reversed = data.clone();
+        reversed.reverse();
+        let again = Backtest::new(10000f64)
+            .run(&reversed, &mut SmaStrategy)
+            .unwrap();
+        assert_eq!(result.fills, again.fills);
+    }
+
+    /// 첫 bar에서 미리 정한 주문을 내는 strategy
+    struct OneShot(Vec<Order>);
+    impl Strategy<Ohlcv> for OneShot {
+        fn on_bar(&mut self, _context: &Context<Ohlcv>) -> Vec<Order> {
+            std::mem::take(&mut self.0)
+        }
+    }
+
+    #[test]
+    fn test_order_fill() {
+        let data = sample_candles();
+        let run = |orders: Vec<Order>| {
+            Backtest::new(10000f64)
+                .run(&data, &mut OneShot(orders))
+                .unwrap()
+        };
+
+        // bar 1: open 100, high 102, low 99
+        let result = run(vec![Order::limit(Side::Buy, 1f64, 99.5)]);
+        assert_eq!(99.5f64, result.fills[0].price);
+        let result = run(vec![Order::limit(Side::Buy, 1f64, 98f64)]);
+        assert!(result.fills.is_empty());
+        // 시가가 이미 limit보다 유리하면 시가에 체결
+        let result = run(vec![Order::limit(Side::Sell, 1f64, 99f64)]);
+        assert_eq!(100f64, result.fills[0].price);
+        let result = run(vec![Order::stop(Side::Buy, 1f64, 101.5)]);
+        assert_eq!(101.5f64, result.fills[0].price);
+        let result = run(vec![Order::stop(Side::Sell, 1f64, 98f64)]);
+        assert!(result.fills.is_empty());
+
+        // short 후 반대 매매로 청산 + 신규 long
+        let result = run(vec![
+            Order::market(Side::Sell, 2f64),
+            Order {
+                side: Side::Buy,
+                kind: OrderKind::Stop(101f64),
+                quantity: 3f64,
+                time_in_force: TimeInForce::Day,
+            },
+        ]);
+        assert_eq!(2, result.fills.len());
+        assert_eq!(1, result.trades.len());
+        assert_eq!(Side::Sell, result.trades[0].side);
+        assert!((result.trades[0].pnl() - (-2f64)).abs() < MAX_ERR);
+        // 1주 long(101)을 bar 7 종가 98로 평가: 10000 + 200 - 303 + 98
+        assert!((result.equity.last().unwrap().value - 9995f64).abs() < MAX_ERR);
+
+        assert!(
+            Backtest::new(10000f64)
+                .run(&data, &mut OneShot(vec![Order::market(Side::Buy, 0f64)]))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_time_in_force() {
+        let data = sample_candles();
+        let run = |order: Order| {
+            Backtest::new(10000f64)
+                .run(&data, &mut OneShot(vec![order]))
+                .unwrap()
+                .fills
+        };
+
+        // low가 96 이하인 bar는 bar 6뿐
+        let order = Order::limit(Side::Buy, 1f64, 96f64);
+        assert!(run(order).is_empty());
+        let fills = run(order.with_time_in_force(TimeInForce::GoodTillCancel));
+        assert_eq!(day(6), fills[0].epoch_time);
+        assert_eq!(96f64, fills[0].price);
+        assert!(run(order.with_time_in_force(TimeInForce::GoodTillTime(day(5)))).is_empty());
+
+        // IOC는 bar 1 시가(100)로만 판단
+        let order =
+            Order::limit(Side::Buy, 1f64, 99.5).with_time_in_force(TimeInForce::ImmediateOrCancel);
+        assert!(run(order).is_empty());
+        let order =
+            Order::limit(Side::Buy, 1f64, 100.5).with_time_in_force(TimeInForce::ImmediateOrCancel);
+        assert_eq!(100f64, run(order)[0].price);
+
+        let fills = run(Order::stop_limit(Side::Buy, 1f64, 101.5, 102f64));
+        assert_eq!(101.5, fills[0].price);
+        // bar 3에서 103.5에 trigger, limit 103 초과로 미체결 → bar 5에서 103에 체결
+        let order = Order::stop_limit(Side::Buy, 1f64, 103.5, 103f64)
+            .with_time_in_force(TimeInForce::GoodTillCancel);
+        let fills = run(order);
+        assert_eq!(day(5), fills[0].epoch_time);
+        assert_eq!(103f64, fills[0].price);
+    }
+
+    #[test]
+    fn test_costs() {
+        let data = sample_candles();
+        let costs = Costs {
+            commission: Some(Commission::Percent(0.001)),
+            min_commission: 1f64,
+            slippage: Some(Slippage::Fixed(0.1)),
+            spread: Some(Spread::Range(0.5)),
+        };
+        let result = Backtest::new(10000f64)
+            .with_costs(costs)
+            .run(&data, &mut SmaStrategy)
+            .unwrap();
+
+        // 매수: bar 3 시가 102 + spread 0.5 * 5 / 2 + slippage 0.1
+        assert!((result.fills[0].price - 103.35).abs() < MAX_ERR);
+        assert!((result.fills[0].commission - 1.0335).abs() < MAX_ERR);
+        // 매도: bar 6 시가 100 - spread 0.5 * 6 / 2 - slippage 0.1, 최소 수수료 1
+        assert!((result.fills[1].price - 98.4).abs() < MAX_ERR);
+        assert_eq!(1f64, result.fills[1].commission);
+
+        let pnl = (98.4 - 103.35) * 10f64 - 1.0335 - 1f64;
+        assert!((result.trades[0].commission - 2.0335).abs() < MAX_ERR);
+        assert!((result.trades[0].pnl() - pnl).abs() < MAX_ERR);
+        assert!((result.equity.last().unwrap().value - (10000f64 + pnl)).abs() < MAX_ERR);
+
+        // 거래량 대비 주문 비율만큼 slippage: 10 / 1000 * 0.1 = 0.1%
+        let costs = Costs {
+            commission: Some(Commission::PerShare(0.01)),
+            slippage: Some(Slippage::Volume(0.1)),
+            ..Default::default()
+        };
+        assert!((costs.fill_price(Side::Buy, 10f64, 100f64, &data[1]) - 100.1).abs() < MAX_ERR);
+        assert!((costs.fill_price(Side::Sell, 10f64, 100f64, &data[1]) - 99.9).abs() < MAX_ERR);
+        assert!((costs.commission(10f64, 100f64) - 0.1).abs() < MAX_ERR);
+        assert_eq!(5f64, Commission::Fixed(5f64).of(10f64, 100f64));
+        // 수수료 모델이 없으면 최소 수수료도 없음
+        let costs = Costs {
+            min_commission: 1f64,
+            ..Default::default()
+        };
+        assert_eq!(0f64, costs.commission(10f64, 100f64));
+
+        // 지정가 주문은 spread를 더해도 지정가보다 불리하게 체결되지 않음
+        let costs = Costs {
+            spread: Some(Spread::Fixed(0.1)),
+            ..Default::default()
+        };
+        let run = |order: Order| {
+            Backtest::new(10000f64)
+                .with_costs(costs)
+                .run(&data, &mut OneShot(vec![order]))
+                .unwrap()
+                .fills
+        };
+        // bar 1: open 100, high 102, low 99
+        let fills = run(Order::limit(Side::Buy, 1f64, 100f64));
+        assert!(fills[0].price <= 100f64);
+        let fills = run(Order::limit(Side::Sell, 1f64, 100f64));
+        assert!(fills[0].price >= 100f64);
+        // 시가 100 + 0.05가 지정가 100.02를 넘으면 지정가에 체결
+        let fills = run(Order::limit(Side::Buy, 1f64, 100.02));
+        assert!((fills[0].price - 100.02).abs() < MAX_ERR);
+        let fills = run(Order::stop_limit(Side::Buy, 1f64, 101.5, 101.5));
+        assert!((fills[0].price - 101.5).abs() < MAX_ERR);
+        // 시장가, stop 주문은 spread를 그대로 부담
+        let fills = run(Order::market(Side::Buy, 1f64));
+        assert!((fills[0].price - 100.05).abs() < MAX_ERR);
+        let fills = run(Order::stop(Side::Buy, 1f64, 101.5));
+        assert!((fills[0].price - 101.55).abs() < MAX_ERR);
+    }
+
+    #[test]
+    fn test_metrics() {
+        let equity = [100f64, 110f64, 99f64, 121f64, 121f64];
+        let trade = |pnl: f64| Trade {
+            side: Side::Buy,
+            quantity: 1f64,
+            entry_time: day(1),
+            entry_price: 100f64,
+            exit_time: day(3),
+            exit_price: 100f64 + pnl,
+            commission: 0f64,
+        };
+        let fill = |side: Side, days: usize| Fill {
+            epoch_time: day(days),
+            side,
+            quantity: 1f64,
+            price: 100f64,
+            commission: 0f64,
+        };
+        let result = BacktestResult {
+            fills: vec![fill(Side::Buy, 1), fill(Side::Sell, 3)],
+            trades: vec![trade(10f64), trade(-5f64), trade(20f64)],
+            equity: equity
+                .iter()
+                .enumerate()
+                .map(|(i, value)| Point::new(*value, day(i)))
+                .collect(),
+        };
+        // 1년 = 4 bar
+        let metrics = Metrics::new(&result, Time::from_days(4), 0.04).unwrap();
+
+        assert!((metrics.equity.total_return - 0.21).abs() < MAX_ERR);
+        assert!((metrics.equity.annualised_return - 0.21).abs() < MAX_ERR);
+        assert!((metrics.equity.volatility - 0.2757705254664636).abs() < MAX_ERR);
+        assert!((metrics.equity.sharpe - 0.6607748305008123).abs() < MAX_ERR);
+        assert!((metrics.equity.sortino - 1.649762483441451).abs() < MAX_ERR);
+        assert!((metrics.equity.max_drawdown - 0.1).abs() < MAX_ERR);
+        assert!((metrics.equity.calmar - 2.1).abs() < MAX_ERR);
+        // 110(day 1)에서 121(day 3)로 회복
+        assert_eq!(Time::from_days(2), metrics.equity.max_drawdown_duration);
+
+        assert!((metrics.trades.win_rate - 2f64 / 3f64).abs() < MAX_ERR);
+        assert!((metrics.trades.profit_factor - 6f64).abs() < MAX_ERR);
+        assert!((metrics.trades.average_trade - 25f64 / 3f64).abs() < MAX_ERR);
+        // average trade 25/3 / average loss 5
+        assert!((metrics.trades.expectancy - 5f64 / 3f64).abs() < MAX_ERR);
+        // day 1, 2 종가에 position 보유
+        assert!((metrics.exposure - 0.4).abs() < MAX_ERR);
+
+        // backtest 밖의 equity curve도 같은 결과
+        let balances: Vec<Point> = result.equity.iter().rev().copied().collect();
+        let equity = EquityMetrics::new(&balances, Time::from_days(4), 0.04).unwrap();
+        assert_eq!(metrics.equity.sharpe, equity.sharpe);
+        assert_eq!(
+            metrics.equity.max_drawdown_duration,
+            equity.max_drawdown_duration
+        );
+        let trades = TradeMetrics::new(&[]);
+        assert_eq!(0f64, trades.win_rate);
+        assert_eq!(0f64, trades.average_trade);
+        assert_eq!(0f64, trades.expectancy);
+        // 손실 거래가 없으면 profit factor와 같이 무한대
+        let trades = TradeMetrics::new(&[trade(10f64), trade(20f64)]);
+        assert_eq!(f64::INFINITY, trades.profit_factor);
+        assert_eq!(f64::INFINITY, trades.expectancy);
+        // average trade -4 / average loss 8
+        let trades = TradeMetrics::new(&[trade(-10f64), trade(4f64), trade(-6f64)]);
+        assert!((trades.average_trade - (-4f64)).abs() < MAX_ERR);
+        assert!((trades.expectancy - (-0.5)).abs() < MAX_ERR);
+
+        let result = BacktestResult {
+            equity: vec![Point::new(100f64, day(0))],
+            ..result
+        };
+        assert!(Metrics::new(&result, Time::from_days(4), 0f64).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use super::{Snapshot, Stateful};
+use crate::types::{
+    data::{BaseData, Candle, CandleColumns},
+    error::ToolkitError,
+};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccumulationDistribution {
+    inner: f64,
+    epoch_time: u128,
+}
+
+// make Accumulation/Distribution Line usable for MovingAverage
+impl BaseData for AccumulationDistribution {
+    fn value(&self) -> f64 {
+        self.inner
+    }
+
+    fn weight(&self) -> u64 {
+        1
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.epoch_time
+    }
+}
+
+impl AccumulationDistribution {
+    /// Accumulation/Distribution Line
+    pub fn new<T>(data: &[T]) -> Result<Self, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        if data.is_empty() {
+            return Err(ToolkitError::EmptyData);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+
+        let mut adl = Self {
+            inner: 0f64,
+            epoch_time: data[0].epoch_time(),
+        };
+        for elem in data.iter() {
+            adl = Self::from_prev(&adl, elem);
+        }
+        Ok(adl)
+    }
+
+    /// Accumulation/Distribution Line from previous value
+    pub fn from_prev<T>(prev: &Self, curr: &T) -> Self
+    where
+        T: Candle,
+    {
+        Self {
+            inner: prev.inner + Self::money_flow_volume(curr),
+            epoch_time: curr.epoch_time(),
+        }
+    }
+
+    /// A/D Line of every bar, accumulated from 0 before the first bar like `new`
+    pub fn columnar(columns: &CandleColumns) -> Result<Vec<f64>, ToolkitError> {
+        if columns.is_empty() {
+            return Err(ToolkitError::EmptyData);
+        }
+        let mut inner = 0f64;
+        Ok((0..columns.len())
+            .map(|i| {
+                inner += Self::money_flow_volume(&columns.candle(i));
+                inner
+            })
+            .collect())
+    }
+
+    /// Money Flow Multiplier * volume of a single bar
+    pub fn money_flow_volume<T>(candle: &T) -> f64
+    where
+        T: Candle,
+    {
+        let range = candle.high_price() - candle.low_price();
+        if range == 0f64 {
+            return 0f64;
+        }
+        let multiplier = ((candle.close_price() - candle.low_price())
+            - (candle.high_price() - candle.close_price()))
+            / range;
+        multiplier * candle.tot_exec_volume() as f64
+    }
+
+    pub fn inner(&self) -> f64 {
+        self.inner
+    }
+}
+
+impl Stateful for AccumulationDistribution {
+    const KIND: &'static str = "accumulation_distribution";
+    const VERSION: u32 = 1;
+
+    /// values: [inner]
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::new::<Self>(Some(self.epoch_time), vec![self.inner])
+    }
+
+    fn restore(snapshot: &Snapshot) -> Result<Self, ToolkitError> {
+        let values = snapshot.values_of::<Self>(1)?;
+        Ok(Self {
+            inner: values[0],
+            epoch_time: snapshot.epoch_time()?,
+        })
+    }
+}
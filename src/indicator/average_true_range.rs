@@ -0,0 +1,136 @@
+use super::{Snapshot, Stateful, columnar};
+use crate::types::{
+    data::{BaseData, Candle, CandleColumns},
+    error::ToolkitError,
+};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AverageTrueRange {
+    inner: f64,
+    /// close price of the latest bar, needed for the next true range
+    close: f64,
+    epoch_time: u128,
+}
+
+impl BaseData for AverageTrueRange {
+    fn value(&self) -> f64 {
+        self.inner
+    }
+
+    fn weight(&self) -> u64 {
+        1
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.epoch_time
+    }
+}
+
+impl AverageTrueRange {
+    /// Average True Range(ATR) with Wilder's smoothing
+    /// requires at least `period + 1` data since a true range needs the previous close
+    pub fn new<T>(data: &[T], period: usize) -> Result<Self, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        Self::series(data, period)?
+            .pop()
+            .ok_or(ToolkitError::DataNotEnough)
+    }
+
+    /// ATR of every bar from the first complete period
+    pub fn series<T>(data: &[T], period: usize) -> Result<Vec<Self>, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        if period == 0 {
+            return Err(ToolkitError::InvalidData);
+        }
+        if data.len() <= period {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+
+        // 첫 period개 true range의 SMA로 seed
+        let seed = data
+            .windows(2)
+            .take(period)
+            .map(|pair| Self::true_range(pair[0].close_price(), &pair[1]))
+            .sum::<f64>()
+            / period as f64;
+        let mut atr = Self {
+            inner: seed,
+            close: data[period].close_price(),
+            epoch_time: data[period].epoch_time(),
+        };
+        let mut result = vec![atr];
+        for elem in data.iter().skip(period + 1) {
+            atr = Self::from_prev(period, &atr, elem);
+            result.push(atr);
+        }
+        Ok(result)
+    }
+
+    /// ATR of every bar
+    /// NaN for the first `period` bars
+    pub fn columnar(columns: &CandleColumns, period: usize) -> Result<Vec<f64>, ToolkitError> {
+        columnar::check(columns.len(), period, period + 1)?;
+        let (high, low, close) = (columns.high(), columns.low(), columns.close());
+        let true_range = |i: usize| high[i].max(close[i - 1]) - low[i].min(close[i - 1]);
+        let mut result = vec![f64::NAN; columns.len()];
+        // 첫 period개 true range의 SMA로 seed
+        let mut atr = (1..=period).map(true_range).sum::<f64>() / period as f64;
+        result[period] = atr;
+        for (i, elem) in result.iter_mut().enumerate().skip(period + 1) {
+            atr = (atr * (period - 1) as f64 + true_range(i)) / period as f64;
+            *elem = atr;
+        }
+        Ok(result)
+    }
+
+    /// Average True Range from previous value
+    pub fn from_prev<T>(period: usize, prev: &Self, curr: &T) -> Self
+    where
+        T: Candle,
+    {
+        let true_range = Self::true_range(prev.close, curr);
+        Self {
+            inner: (prev.inner * (period - 1) as f64 + true_range) / period as f64,
+            close: curr.close_price(),
+            epoch_time: curr.epoch_time(),
+        }
+    }
+
+    /// True Range of a bar given the previous close price
+    pub fn true_range<T>(prev_close: f64, curr: &T) -> f64
+    where
+        T: Candle,
+    {
+        curr.high_price().max(prev_close) - curr.low_price().min(prev_close)
+    }
+
+    pub fn inner(&self) -> f64 {
+        self.inner
+    }
+}
+
+impl Stateful for AverageTrueRange {
+    const KIND: &'static str = "average_true_range";
+    const VERSION: u32 = 1;
+
+    /// values: [inner, close]
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::new::<Self>(Some(self.epoch_time), vec![self.inner, self.close])
+    }
+
+    fn restore(snapshot: &Snapshot) -> Result<Self, ToolkitError> {
+        let values = snapshot.values_of::<Self>(2)?;
+        Ok(Self {
+            inner: values[0],
+            close: values[1],
+            epoch_time: snapshot.epoch_time()?,
+        })
+    }
+}
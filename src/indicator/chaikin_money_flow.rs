@@ -0,0 +1,79 @@
+use super::{AccumulationDistribution, columnar};
+use crate::types::{
+    data::{BaseData, Candle, CandleColumns},
+    error::ToolkitError,
+};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChaikinMoneyFlow {
+    inner: f64,
+    epoch_time: u128,
+}
+
+impl BaseData for ChaikinMoneyFlow {
+    fn value(&self) -> f64 {
+        self.inner
+    }
+
+    fn weight(&self) -> u64 {
+        1
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.epoch_time
+    }
+}
+
+impl ChaikinMoneyFlow {
+    /// Chaikin Money Flow(CMF)
+    /// sum of money flow volume / sum of volume over the whole data
+    pub fn new<T>(data: &[T]) -> Result<Self, ToolkitError>
+    where
+        T: Candle,
+    {
+        let last_epoch_time = data
+            .iter()
+            .map(|elem| elem.epoch_time())
+            .max()
+            .ok_or(ToolkitError::EmptyData)?;
+        let mut money_flow_volume = 0f64;
+        let mut volume = 0f64;
+        for elem in data.iter() {
+            money_flow_volume += AccumulationDistribution::money_flow_volume(elem);
+            volume += elem.tot_exec_volume() as f64;
+        }
+        if volume == 0f64 {
+            return Err(ToolkitError::InvalidData);
+        }
+        Ok(Self {
+            inner: money_flow_volume / volume,
+            epoch_time: last_epoch_time,
+        })
+    }
+
+    /// CMF of every bar over the latest `period` bars
+    /// NaN until the first complete period, and where the volume of the period is 0
+    pub fn columnar(columns: &CandleColumns, period: usize) -> Result<Vec<f64>, ToolkitError> {
+        columnar::check(columns.len(), period, period)?;
+        let money_flow_volumes: Vec<f64> = (0..columns.len())
+            .map(|i| AccumulationDistribution::money_flow_volume(&columns.candle(i)))
+            .collect();
+        let volumes: Vec<f64> = columns.volume().iter().map(|v| *v as f64).collect();
+        Ok(columnar::rolling_sum(&money_flow_volumes, period)
+            .iter()
+            .zip(columnar::rolling_sum(&volumes, period).iter())
+            .map(|(money_flow_volume, volume)| {
+                if *volume == 0f64 {
+                    f64::NAN
+                } else {
+                    money_flow_volume / volume
+                }
+            })
+            .collect())
+    }
+
+    pub fn inner(&self) -> f64 {
+        self.inner
+    }
+}
@@ -0,0 +1,113 @@
+use super::{AccumulationDistribution, MovingAverage, Snapshot, Stateful};
+use crate::types::{
+    data::{BaseData, Candle, CandleColumns},
+    error::ToolkitError,
+};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChaikinOscillator {
+    ema_3: f64,
+    ema_10: f64,
+    epoch_time: u128,
+}
+
+impl ChaikinOscillator {
+    /// Chaikin Oscillator
+    /// EMA(3) - EMA(10) of the Accumulation/Distribution Line
+    /// both EMAs are seeded with the A/D Line of the oldest bar like TA-Lib, the output starts at the 10th bar
+    pub fn new<T>(data: &[T]) -> Result<Self, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        if data.len() < 10 {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+
+        // 두 EMA 모두 첫 bar의 A/D Line으로 seed → 이후 rolling
+        let mut adl = AccumulationDistribution::new(&data[0..1])?;
+        let mut oscillator = Self {
+            ema_3: adl.inner(),
+            ema_10: adl.inner(),
+            epoch_time: adl.epoch_time(),
+        };
+        for elem in data.iter().skip(1) {
+            adl = AccumulationDistribution::from_prev(&adl, elem);
+            oscillator = Self::from_prev(&oscillator, &adl);
+        }
+        Ok(oscillator)
+    }
+
+    /// Chaikin Oscillator from previous value and the new A/D Line value
+    pub fn from_prev(prev: &Self, adl: &AccumulationDistribution) -> Self {
+        let ema_3 =
+            MovingAverage::exponential_from(3, &MovingAverage::Exponential(prev.ema_3), adl);
+        let ema_10 =
+            MovingAverage::exponential_from(10, &MovingAverage::Exponential(prev.ema_10), adl);
+        Self {
+            ema_3: ema_3.inner(),
+            ema_10: ema_10.inner(),
+            epoch_time: adl.epoch_time(),
+        }
+    }
+
+    /// Chaikin Oscillator of every bar
+    /// NaN for the first 9 bars
+    pub fn columnar(columns: &CandleColumns) -> Result<Vec<f64>, ToolkitError> {
+        if columns.len() < 10 {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        let adls = AccumulationDistribution::columnar(columns)?;
+        // from_prev와 같은 식(exponential_from)으로 rolling
+        let (k_3, k_10) = (2f64 / 4f64, 2f64 / 11f64);
+        let (mut ema_3, mut ema_10) = (adls[0], adls[0]);
+        let mut result = vec![f64::NAN; 9];
+        for (i, adl) in adls.iter().enumerate().skip(1) {
+            ema_3 = adl * k_3 + ema_3 * (1f64 - k_3);
+            ema_10 = adl * k_10 + ema_10 * (1f64 - k_10);
+            if i >= 9 {
+                result.push(ema_3 - ema_10);
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn inner(&self) -> f64 {
+        self.ema_3 - self.ema_10
+    }
+}
+
+impl BaseData for ChaikinOscillator {
+    fn value(&self) -> f64 {
+        self.inner()
+    }
+
+    fn weight(&self) -> u64 {
+        1
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.epoch_time
+    }
+}
+
+impl Stateful for ChaikinOscillator {
+    const KIND: &'static str = "chaikin_oscillator";
+    const VERSION: u32 = 1;
+
+    /// values: [ema_3, ema_10]
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::new::<Self>(Some(self.epoch_time), vec![self.ema_3, self.ema_10])
+    }
+
+    fn restore(snapshot: &Snapshot) -> Result<Self, ToolkitError> {
+        let values = snapshot.values_of::<Self>(2)?;
+        Ok(Self {
+            ema_3: values[0],
+            ema_10: values[1],
+            epoch_time: snapshot.epoch_time()?,
+        })
+    }
+}
@@ -1,33 +1,62 @@
-use super::MovingAverage;
+use super::{MovingAverage, columnar};
+use crate::stats::{LinearRegression, RollingStats};
 use crate::types::{
-    data::{BaseData, Candle},
+    data::{BaseData, Candle, Point},
     error::ToolkitError,
 };
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Channel {
     Envelope(Band),
     Bollinger(Band),
+    LinearRegression(Band),
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Band {
     pub upper: f64,
     pub mid: f64,
     pub lower: f64,
 }
 
+/// bands of every bar, NaN until the first complete period
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BandColumns {
+    pub upper: Vec<f64>,
+    pub mid: Vec<f64>,
+    pub lower: Vec<f64>,
+}
+
+impl BandColumns {
+    fn new(mid: Vec<f64>, width: impl Fn(usize, f64) -> f64) -> Self {
+        let (upper, lower) = mid
+            .iter()
+            .enumerate()
+            .map(|(i, mid)| {
+                let width = width(i, *mid);
+                (mid + width, mid - width)
+            })
+            .unzip();
+        Self { upper, mid, lower }
+    }
+}
+
 impl Channel {
     pub fn inner(&self) -> Band {
         match self {
-            Channel::Envelope(band) | Channel::Bollinger(band) => band.clone(),
+            Channel::Envelope(band)
+            | Channel::Bollinger(band)
+            | Channel::LinearRegression(band) => *band,
         }
     }
     pub fn envelope<T>(data: &[T], coefficient: f64) -> Self
     where
         T: BaseData + Clone,
     {
-        let ema = MovingAverage::exponential(&data).inner();
+        let ema = MovingAverage::exponential(data).inner();
         Self::Envelope(Band {
             upper: ema * (1f64 + coefficient),
             mid: ema,
@@ -43,35 +72,117 @@ impl Channel {
         })
     }
 
+    /// Envelope of every bar from sorted values, around `MovingAverage::exponential_columnar`
+    pub fn envelope_columnar(
+        values: &[f64],
+        period: usize,
+        coefficient: f64,
+    ) -> Result<BandColumns, ToolkitError> {
+        columnar::check(values.len(), period, period)?;
+        Ok(BandColumns::new(
+            columnar::exponential(values, period),
+            |_, mid| mid * coefficient,
+        ))
+    }
+
     pub fn bollinger<T>(data: &[T], dev_mul: f64, exponential: bool) -> Result<Self, ToolkitError>
     where
         T: Candle + BaseData + Clone,
     {
-        if data.len() == 0 {
+        if data.is_empty() {
             return Err(ToolkitError::EmptyData);
         }
         let mut data = data.to_owned();
         data.sort_by_key(|k| Candle::epoch_time(k));
-        let mut sum = 0f64;
-        for elem in data.iter() {
-            sum += elem.close_price();
-        }
-        let mean = sum / (data.len() as f64);
+        let stats = data.iter().fold(RollingStats::default(), |stats, elem| {
+            stats.add(elem.close_price())
+        });
 
         let mid = if exponential {
             MovingAverage::exponential(&data).inner()
         } else {
-            mean
+            stats.mean()
         };
 
-        let mut variation = 0f64;
-        for elem in data.iter() {
-            variation += (mid - elem.close_price()).powi(2);
-        }
-        let stdev = (variation / data.len() as f64).sqrt();
+        let stdev = stats.stdev_around(mid);
         let upper = mid + dev_mul * stdev;
         let lower = mid - dev_mul * stdev;
 
         Ok(Self::Bollinger(Band { upper, mid, lower }))
     }
+
+    /// Bollinger Bands of every bar over the latest `period` sorted values
+    /// SMA ± `dev_mul` * population standard deviation, same as `bollinger` without `exponential`
+    pub fn bollinger_columnar(
+        values: &[f64],
+        period: usize,
+        dev_mul: f64,
+    ) -> Result<BandColumns, ToolkitError> {
+        columnar::check(values.len(), period, period)?;
+        let mut mids = vec![f64::NAN; values.len()];
+        let mut stdevs = vec![f64::NAN; values.len()];
+        let mut stats = RollingStats::default();
+        for (i, value) in values.iter().enumerate() {
+            stats = stats.add(*value);
+            if i >= period {
+                stats = stats.remove(values[i - period]);
+            }
+            if i + 1 >= period {
+                mids[i] = stats.mean();
+                stdevs[i] = stats.stdev();
+            }
+        }
+        Ok(BandColumns::new(mids, |i, _| dev_mul * stdevs[i]))
+    }
+
+    /// Linear Regression Channel of every bar over the latest `period` sorted values
+    pub fn linear_regression_columnar(
+        values: &[f64],
+        period: usize,
+        dev_mul: f64,
+    ) -> Result<BandColumns, ToolkitError> {
+        if period < 2 {
+            return Err(ToolkitError::InvalidData);
+        }
+        columnar::check(values.len(), period, period)?;
+        let points: Vec<Point> = values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| Point::new(*value, i as u128))
+            .collect();
+        let mut mids = vec![f64::NAN; values.len()];
+        let mut stdevs = vec![f64::NAN; values.len()];
+        let mut regression = LinearRegression::new(&points[..period])?;
+        for i in period - 1..values.len() {
+            if i >= period {
+                regression =
+                    LinearRegression::from_prev(&regression, &points[i - period], &points[i]);
+            }
+            mids[i] = regression.end();
+            stdevs[i] = regression.residual_stdev();
+        }
+        Ok(BandColumns::new(mids, |i, _| dev_mul * stdevs[i]))
+    }
+
+    /// Linear Regression Channel
+    /// regression line at the latest bar ± `dev_mul` * standard deviation of the residuals
+    pub fn linear_regression<T>(data: &[T], dev_mul: f64) -> Result<Self, ToolkitError>
+    where
+        T: BaseData + Clone,
+    {
+        Ok(Self::linear_regression_from(
+            LinearRegression::new(data)?,
+            dev_mul,
+        ))
+    }
+
+    pub fn linear_regression_from(regression: LinearRegression, dev_mul: f64) -> Self {
+        let mid = regression.end();
+        let stdev = regression.residual_stdev();
+        Self::LinearRegression(Band {
+            upper: mid + dev_mul * stdev,
+            mid,
+            lower: mid - dev_mul * stdev,
+        })
+    }
 }
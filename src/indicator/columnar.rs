@@ -0,0 +1,82 @@
+use crate::types::error::ToolkitError;
+use std::collections::VecDeque;
+
+/// checks the period and that at least `min_len` values are given
+pub(crate) fn check(len: usize, period: usize, min_len: usize) -> Result<(), ToolkitError> {
+    if period == 0 {
+        return Err(ToolkitError::InvalidData);
+    }
+    if len < min_len {
+        return Err(ToolkitError::DataNotEnough);
+    }
+    Ok(())
+}
+
+/// sum of the latest `period` values
+pub(crate) fn rolling_sum(values: &[f64], period: usize) -> Vec<f64> {
+    let mut result = vec![f64::NAN; values.len()];
+    let mut sum = 0f64;
+    for (i, value) in values.iter().enumerate() {
+        sum += value;
+        if i >= period {
+            sum -= values[i - period];
+        }
+        if i + 1 >= period {
+            result[i] = sum;
+        }
+    }
+    result
+}
+
+/// highest of the latest `period` values(monotonic deque)
+pub(crate) fn rolling_max(values: &[f64], period: usize) -> Vec<f64> {
+    rolling_extreme(values, period, |kept, new| kept <= new)
+}
+
+/// lowest of the latest `period` values(monotonic deque)
+pub(crate) fn rolling_min(values: &[f64], period: usize) -> Vec<f64> {
+    rolling_extreme(values, period, |kept, new| kept >= new)
+}
+
+fn rolling_extreme<F>(values: &[f64], period: usize, dominated: F) -> Vec<f64>
+where
+    F: Fn(f64, f64) -> bool,
+{
+    let mut result = vec![f64::NAN; values.len()];
+    // 극값 후보 index, 앞쪽이 현재 window의 극값
+    let mut candidates: VecDeque<usize> = VecDeque::with_capacity(period);
+    for (i, value) in values.iter().enumerate() {
+        while candidates
+            .back()
+            .is_some_and(|back| dominated(values[*back], *value))
+        {
+            candidates.pop_back();
+        }
+        candidates.push_back(i);
+        if candidates.front().is_some_and(|front| *front + period <= i) {
+            candidates.pop_front();
+        }
+        if i + 1 >= period {
+            // it's safe since the current index was just pushed
+            result[i] = values[*candidates.front().unwrap()];
+        }
+    }
+    result
+}
+
+/// EMA(`period`) seeded with the SMA of the first `period` values
+/// same as `MovingAverage::simple` followed by `MovingAverage::exponential_from`
+pub(crate) fn exponential(values: &[f64], period: usize) -> Vec<f64> {
+    let mut result = vec![f64::NAN; values.len()];
+    if period == 0 || values.len() < period {
+        return result;
+    }
+    let k = 2f64 / ((period + 1) as f64);
+    let mut ema = values[..period].iter().sum::<f64>() / period as f64;
+    result[period - 1] = ema;
+    for (i, value) in values.iter().enumerate().skip(period) {
+        ema = value * k + ema * (1f64 - k);
+        result[i] = ema;
+    }
+    result
+}
@@ -0,0 +1,100 @@
+use super::columnar;
+use crate::types::{
+    data::{BaseData, Candle, CandleColumns},
+    error::ToolkitError,
+};
+
+/// Lambert's constant which makes ~70-80% of CCI values fall between -100 and 100
+static LAMBERT_CONSTANT: f64 = 0.015f64;
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CommodityChannelIndex {
+    inner: f64,
+    epoch_time: u128,
+}
+
+impl BaseData for CommodityChannelIndex {
+    fn value(&self) -> f64 {
+        self.inner
+    }
+
+    fn weight(&self) -> u64 {
+        1
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.epoch_time
+    }
+}
+
+impl CommodityChannelIndex {
+    /// Commodity Channel Index(CCI) over the latest `period` bars
+    /// NaN if the mean deviation is 0
+    pub fn new<T>(data: &[T], period: usize) -> Result<Self, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        if period == 0 {
+            return Err(ToolkitError::InvalidData);
+        }
+        if data.len() < period {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+        let window = &data[data.len() - period..];
+
+        let typical_prices: Vec<f64> = window
+            .iter()
+            .map(|elem| (elem.high_price() + elem.low_price() + elem.close_price()) / 3f64)
+            .collect();
+        let mean = typical_prices.iter().sum::<f64>() / period as f64;
+        let mean_deviation = typical_prices
+            .iter()
+            .map(|tp| (tp - mean).abs())
+            .sum::<f64>()
+            / period as f64;
+        let last_typical_price = typical_prices.last().unwrap(); // it's safe since period > 0
+
+        let inner = if mean_deviation == 0f64 {
+            f64::NAN
+        } else {
+            (last_typical_price - mean) / (LAMBERT_CONSTANT * mean_deviation)
+        };
+        Ok(Self {
+            inner,
+            epoch_time: window.last().unwrap().epoch_time(), // it's safe since period > 0
+        })
+    }
+
+    /// CCI of every bar
+    /// NaN until the first complete period, and where the mean deviation is 0
+    pub fn columnar(columns: &CandleColumns, period: usize) -> Result<Vec<f64>, ToolkitError> {
+        columnar::check(columns.len(), period, period)?;
+        let typical_prices: Vec<f64> = columns
+            .high()
+            .iter()
+            .zip(columns.low().iter())
+            .zip(columns.close().iter())
+            .map(|((high, low), close)| (high + low + close) / 3f64)
+            .collect();
+        let mut result = vec![f64::NAN; period - 1];
+        // mean deviation은 window마다 다시 계산
+        result.extend(typical_prices.windows(period).map(|window| {
+            let mean = window.iter().sum::<f64>() / period as f64;
+            let mean_deviation =
+                window.iter().map(|tp| (tp - mean).abs()).sum::<f64>() / period as f64;
+            if mean_deviation == 0f64 {
+                f64::NAN
+            } else {
+                (window[period - 1] - mean) / (LAMBERT_CONSTANT * mean_deviation)
+            }
+        }));
+        Ok(result)
+    }
+
+    pub fn inner(&self) -> f64 {
+        self.inner
+    }
+}
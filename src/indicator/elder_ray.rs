@@ -1,10 +1,11 @@
-use super::MovingAverage;
+use super::{MovingAverage, columnar};
 use crate::types::{
-    data::{BaseData, Candle},
+    data::{BaseData, Candle, CandleColumns},
     error::ToolkitError,
 };
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ElderRay {
     /// seller's force
     ask_force: f64,
@@ -17,7 +18,7 @@ impl ElderRay {
     where
         T: Candle + BaseData + Clone,
     {
-        if data.len() == 0 {
+        if data.is_empty() {
             return Err(ToolkitError::EmptyData);
         }
         let mut sorted = data.to_vec().clone();
@@ -32,6 +33,29 @@ impl ElderRay {
         })
     }
 
+    /// (ask force, bid force) of every bar against the EMA(`period`) of close prices
+    /// the EMA is `MovingAverage::exponential_columnar`, NaN until the first complete period
+    pub fn columnar(
+        columns: &CandleColumns,
+        period: usize,
+    ) -> Result<(Vec<f64>, Vec<f64>), ToolkitError> {
+        columnar::check(columns.len(), period, period)?;
+        let ema = columnar::exponential(columns.close(), period);
+        let ask_forces = columns
+            .low()
+            .iter()
+            .zip(ema.iter())
+            .map(|(low, ema)| low - ema)
+            .collect();
+        let bid_forces = columns
+            .high()
+            .iter()
+            .zip(ema.iter())
+            .map(|(high, ema)| high - ema)
+            .collect();
+        Ok((ask_forces, bid_forces))
+    }
+
     pub fn ask_force(&self) -> f64 {
         self.ask_force
     }
@@ -1,6 +1,10 @@
-use crate::types::data::{BaseData, Candle};
+use crate::types::{
+    data::{BaseData, Candle, CandleColumns},
+    error::ToolkitError,
+};
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ForceIndex {
     inner: f64,
     epoch_time: u128,
@@ -32,6 +36,19 @@ impl ForceIndex {
         }
     }
 
+    /// Force Index of every bar
+    /// NaN at the first bar
+    pub fn columnar(columns: &CandleColumns) -> Result<Vec<f64>, ToolkitError> {
+        if columns.len() < 2 {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        let (close, volume) = (columns.close(), columns.volume());
+        let mut result = Vec::with_capacity(columns.len());
+        result.push(f64::NAN);
+        result.extend((1..columns.len()).map(|i| (close[i] - close[i - 1]) * volume[i] as f64));
+        Ok(result)
+    }
+
     pub fn inner(&self) -> f64 {
         self.inner
     }
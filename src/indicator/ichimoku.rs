@@ -0,0 +1,150 @@
+use super::columnar;
+use crate::types::{
+    data::{Candle, CandleColumns, Point},
+    error::ToolkitError,
+    time::Time,
+};
+
+/// Ichimoku Kinko Hyo
+/// Senkou Span A/B are displaced forward and Chikou Span is displaced backward,
+/// so their epoch times are shifted by `displacement` bar intervals
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ichimoku {
+    /// conversion line
+    pub tenkan: Point,
+    /// base line
+    pub kijun: Point,
+    /// leading span A
+    pub senkou_a: Point,
+    /// leading span B
+    pub senkou_b: Point,
+    /// lagging span
+    pub chikou: Point,
+}
+
+/// Ichimoku lines of every bar, NaN until the first complete senkou B period
+/// values are at the bar they're computed from, the senkou spans are plotted `displacement` bars ahead
+/// and the chikou span is the close plotted `displacement` bars behind
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IchimokuColumns {
+    pub tenkan: Vec<f64>,
+    pub kijun: Vec<f64>,
+    pub senkou_a: Vec<f64>,
+    pub senkou_b: Vec<f64>,
+}
+
+impl Ichimoku {
+    /// Ichimoku of the latest bar
+    /// the classic periods are 9(tenkan), 26(kijun), 52(senkou B) and 26(displacement)
+    pub fn new<T>(
+        data: &[T],
+        tenkan: usize,
+        kijun: usize,
+        senkou_b: usize,
+        displacement: usize,
+    ) -> Result<Self, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        Self::series(data, tenkan, kijun, senkou_b, displacement)?
+            .pop()
+            .ok_or(ToolkitError::DataNotEnough)
+    }
+
+    /// Ichimoku of every bar from the first complete senkou B period
+    pub fn series<T>(
+        data: &[T],
+        tenkan: usize,
+        kijun: usize,
+        senkou_b: usize,
+        displacement: usize,
+    ) -> Result<Vec<Self>, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        if tenkan == 0 || kijun == 0 || senkou_b == 0 {
+            return Err(ToolkitError::InvalidData);
+        }
+        let longest = tenkan.max(kijun).max(senkou_b);
+        if data.len() < longest.max(2) {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+        let epoch_times: Vec<u128> = data.iter().map(|elem| elem.epoch_time()).collect();
+        let shift = Time::interval_of(&epoch_times)? * displacement;
+
+        // (highest high + lowest low) / 2 of the window which ends at `end`
+        let mid_price = |end: usize, period: usize| -> f64 {
+            let mut max_high_price = f64::MIN;
+            let mut min_low_price = f64::MAX;
+            for elem in data[end + 1 - period..=end].iter() {
+                max_high_price = max_high_price.max(elem.high_price());
+                min_low_price = min_low_price.min(elem.low_price());
+            }
+            (max_high_price + min_low_price) / 2f64
+        };
+
+        Ok((longest - 1..data.len())
+            .map(|i| {
+                let epoch_time = Time::from(epoch_times[i]);
+                let tenkan = mid_price(i, tenkan);
+                let kijun = mid_price(i, kijun);
+                let leading_epoch_time = (epoch_time + shift).inner();
+                Self {
+                    tenkan: Point::new(tenkan, epoch_time.inner()),
+                    kijun: Point::new(kijun, epoch_time.inner()),
+                    senkou_a: Point::new((tenkan + kijun) / 2f64, leading_epoch_time),
+                    senkou_b: Point::new(mid_price(i, senkou_b), leading_epoch_time),
+                    chikou: Point::new(
+                        data[i].close_price(),
+                        epoch_time.inner().saturating_sub(shift.inner()),
+                    ),
+                }
+            })
+            .collect())
+    }
+
+    /// Ichimoku lines of every bar, same values as `series`
+    pub fn columnar(
+        columns: &CandleColumns,
+        tenkan: usize,
+        kijun: usize,
+        senkou_b: usize,
+    ) -> Result<IchimokuColumns, ToolkitError> {
+        if tenkan == 0 || kijun == 0 || senkou_b == 0 {
+            return Err(ToolkitError::InvalidData);
+        }
+        let longest = tenkan.max(kijun).max(senkou_b);
+        if columns.len() < longest {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        // (highest high + lowest low) / 2 of every window, NaN before the longest period
+        let mid_prices = |period: usize| -> Vec<f64> {
+            let highs = columnar::rolling_max(columns.high(), period);
+            let lows = columnar::rolling_min(columns.low(), period);
+            let mut mid_prices: Vec<f64> = highs
+                .iter()
+                .zip(lows.iter())
+                .map(|(high, low)| (high + low) / 2f64)
+                .collect();
+            mid_prices[..longest - 1].fill(f64::NAN);
+            mid_prices
+        };
+        let tenkan = mid_prices(tenkan);
+        let kijun = mid_prices(kijun);
+        let senkou_a = tenkan
+            .iter()
+            .zip(kijun.iter())
+            .map(|(tenkan, kijun)| (tenkan + kijun) / 2f64)
+            .collect();
+        Ok(IchimokuColumns {
+            tenkan,
+            kijun,
+            senkou_a,
+            senkou_b: mid_prices(senkou_b),
+        })
+    }
+}
@@ -1,14 +1,32 @@
-use super::MovingAverage;
+use super::{MovingAverage, Snapshot, Stateful, columnar};
 use crate::types::{data::BaseData, error::ToolkitError};
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MovingAverageConvergenceDivergence {
     signal: f64,
     ema_12: f64,
     ema_26: f64,
 }
 
+// MACD 값을 BaseData로 wrapping하여 MovingAverage에 활용
+#[derive(Debug, Clone, Copy)]
+struct MacdPoint(f64, u128);
+impl BaseData for MacdPoint {
+    fn value(&self) -> f64 {
+        self.0
+    }
+    fn weight(&self) -> u64 {
+        1
+    }
+    fn epoch_time(&self) -> u128 {
+        self.1
+    }
+}
+
 impl MovingAverageConvergenceDivergence {
+    /// MACD(12, 26, 9)
+    /// EMA(12) and EMA(26) both start at the 26th bar like TA-Lib, EMA(12) seeded with the SMA of the 15th to 26th bars
     pub fn new<T>(data: &[T]) -> Result<Self, ToolkitError>
     where
         T: BaseData + Clone,
@@ -18,30 +36,11 @@ impl MovingAverageConvergenceDivergence {
         }
         let mut data = data.to_vec();
         data.sort_by_key(|k| k.epoch_time());
-        let n = data.len();
-
-        // MACD 값을 BaseData로 wrapping하여 MovingAverage에 활용
-        #[derive(Debug, Clone, Copy)]
-        struct MacdPoint(f64, u128);
-        impl BaseData for MacdPoint {
-            fn value(&self) -> f64 {
-                self.0
-            }
-            fn weight(&self) -> u64 {
-                1
-            }
-            fn epoch_time(&self) -> u128 {
-                self.1
-            }
-        }
 
         // EMA(26): 첫 26개 SMA로 seed → bar 26부터 rolling
         let mut ema26 = MovingAverage::simple(&data[0..26]);
-        // EMA(12): 첫 12개 SMA로 seed → bar 25까지 rolling (ema26과 기준 bar 맞춤)
-        let mut ema12 = MovingAverage::simple(&data[0..12]);
-        for i in 12..26 {
-            ema12 = MovingAverage::exponential_from(12, &ema12, &data[i]);
-        }
+        // EMA(12): TA-Lib처럼 bar 25까지 12개(bar 14..25)의 SMA로 seed (ema26과 기준 bar 맞춤)
+        let mut ema12 = MovingAverage::simple(&data[14..26]);
 
         // bar 25 기준 첫 번째 MACD 값
         let mut macd_for_seed = vec![MacdPoint(
@@ -50,21 +49,18 @@ impl MovingAverageConvergenceDivergence {
         )];
 
         // bars 26..33: MACD seed용 9개 수집 (총 9개)
-        for i in 26..34 {
-            ema12 = MovingAverage::exponential_from(12, &ema12, &data[i]);
-            ema26 = MovingAverage::exponential_from(26, &ema26, &data[i]);
-            macd_for_seed.push(MacdPoint(
-                ema12.inner() - ema26.inner(),
-                data[i].epoch_time(),
-            ));
+        for elem in data.iter().take(34).skip(26) {
+            ema12 = MovingAverage::exponential_from(12, &ema12, elem);
+            ema26 = MovingAverage::exponential_from(26, &ema26, elem);
+            macd_for_seed.push(MacdPoint(ema12.inner() - ema26.inner(), elem.epoch_time()));
         }
 
         // Signal: 첫 9개 MACD의 SMA로 seed → bar 34부터 rolling EMA(9)
         let mut signal = MovingAverage::simple(&macd_for_seed);
-        for i in 34..n {
-            ema12 = MovingAverage::exponential_from(12, &ema12, &data[i]);
-            ema26 = MovingAverage::exponential_from(26, &ema26, &data[i]);
-            let macd_point = MacdPoint(ema12.inner() - ema26.inner(), data[i].epoch_time());
+        for elem in data.iter().skip(34) {
+            ema12 = MovingAverage::exponential_from(12, &ema12, elem);
+            ema26 = MovingAverage::exponential_from(26, &ema26, elem);
+            let macd_point = MacdPoint(ema12.inner() - ema26.inner(), elem.epoch_time());
             signal = MovingAverage::exponential_from(9, &signal, &macd_point);
         }
 
@@ -75,6 +71,50 @@ impl MovingAverageConvergenceDivergence {
         })
     }
 
+    /// MACD from previous value
+    /// gives the same result as `new` with the new data appended
+    pub fn from_prev<T>(prev: &Self, new_data: &T) -> Self
+    where
+        T: BaseData + Clone,
+    {
+        let ema12 =
+            MovingAverage::exponential_from(12, &MovingAverage::Exponential(prev.ema_12), new_data);
+        let ema26 =
+            MovingAverage::exponential_from(26, &MovingAverage::Exponential(prev.ema_26), new_data);
+        let macd_point = MacdPoint(ema12.inner() - ema26.inner(), new_data.epoch_time());
+        let signal = MovingAverage::exponential_from(
+            9,
+            &MovingAverage::Exponential(prev.signal),
+            &macd_point,
+        );
+        Self {
+            signal: signal.inner(),
+            ema_12: ema12.inner(),
+            ema_26: ema26.inner(),
+        }
+    }
+
+    /// (MACD line, Signal) of every bar from sorted values
+    /// the MACD line starts at the 26th bar and the Signal at the 34th like `new`, NaN before
+    pub fn columnar(values: &[f64]) -> Result<(Vec<f64>, Vec<f64>), ToolkitError> {
+        if values.len() < 34 {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        // EMA(12)는 bar 14..25의 SMA로 seed
+        let mut ema12 = vec![f64::NAN; 14];
+        ema12.extend(columnar::exponential(&values[14..], 12));
+        let ema26 = columnar::exponential(values, 26);
+        let fast: Vec<f64> = ema12
+            .iter()
+            .zip(ema26.iter())
+            .map(|(ema12, ema26)| ema12 - ema26)
+            .collect();
+        // Signal: 첫 9개 MACD의 SMA로 seed
+        let mut slow = vec![f64::NAN; 25];
+        slow.extend(columnar::exponential(&fast[25..], 9));
+        Ok((fast, slow))
+    }
+
     /// fast line / MACD line
     pub fn fast(&self) -> f64 {
         self.ema_12 - self.ema_26
@@ -90,3 +130,22 @@ impl MovingAverageConvergenceDivergence {
         self.fast() - self.slow()
     }
 }
+
+impl Stateful for MovingAverageConvergenceDivergence {
+    const KIND: &'static str = "macd";
+    const VERSION: u32 = 1;
+
+    /// values: [ema_12, ema_26, signal]
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::new::<Self>(None, vec![self.ema_12, self.ema_26, self.signal])
+    }
+
+    fn restore(snapshot: &Snapshot) -> Result<Self, ToolkitError> {
+        let values = snapshot.values_of::<Self>(3)?;
+        Ok(Self {
+            ema_12: values[0],
+            ema_26: values[1],
+            signal: values[2],
+        })
+    }
+}
@@ -1,14 +1,57 @@
+mod accumulation_distribution;
+mod average_true_range;
+mod chaikin_money_flow;
+mod chaikin_oscillator;
 mod channel;
+mod columnar;
+mod commodity_channel_index;
 mod elder_ray;
 mod force_index;
+mod ichimoku;
 mod macd;
+mod momentum;
+mod money_flow_index;
 mod moving_average;
+mod on_balance_volume;
+mod parabolic_sar;
+mod pivot;
+mod rate_of_change;
+mod snapshot;
 mod stochastic;
+mod super_trend;
+mod swing;
 mod test;
+mod trend;
+mod trix;
+mod ultimate_oscillator;
+mod volatility;
+mod williams_r;
+mod zigzag;
 
-pub use channel::{Band, Channel};
+pub use accumulation_distribution::AccumulationDistribution;
+pub use average_true_range::AverageTrueRange;
+pub use chaikin_money_flow::ChaikinMoneyFlow;
+pub use chaikin_oscillator::ChaikinOscillator;
+pub use channel::{Band, BandColumns, Channel};
+pub use commodity_channel_index::CommodityChannelIndex;
 pub use elder_ray::ElderRay;
 pub use force_index::ForceIndex;
+pub use ichimoku::{Ichimoku, IchimokuColumns};
 pub use macd::MovingAverageConvergenceDivergence;
+pub use momentum::Momentum;
+pub use money_flow_index::MoneyFlowIndex;
 pub use moving_average::MovingAverage;
-pub use stochastic::Stochastic;
+pub use on_balance_volume::OnBalanceVolume;
+pub use parabolic_sar::ParabolicSar;
+pub use pivot::{Pivot, PivotLevels, SessionPivot};
+pub use rate_of_change::RateOfChange;
+pub use snapshot::{Snapshot, Stateful};
+pub use stochastic::{Stochastic, StochasticWindow};
+pub use super_trend::SuperTrend;
+pub use swing::{SwingKind, SwingPoint};
+pub use trend::Trend;
+pub use trix::Trix;
+pub use ultimate_oscillator::UltimateOscillator;
+pub use volatility::Volatility;
+pub use williams_r::WilliamsR;
+pub use zigzag::ZigZag;
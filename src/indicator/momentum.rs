@@ -0,0 +1,66 @@
+use super::columnar;
+use crate::types::{data::BaseData, error::ToolkitError};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Momentum {
+    inner: f64,
+    epoch_time: u128,
+}
+
+impl BaseData for Momentum {
+    fn value(&self) -> f64 {
+        self.inner
+    }
+
+    fn weight(&self) -> u64 {
+        1
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.epoch_time
+    }
+}
+
+impl Momentum {
+    /// Momentum
+    /// latest value - value of `period` bars ago
+    pub fn new<T>(data: &[T], period: usize) -> Result<Self, ToolkitError>
+    where
+        T: BaseData + Clone,
+    {
+        if period == 0 {
+            return Err(ToolkitError::InvalidData);
+        }
+        if data.len() <= period {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+        let last = data.last().unwrap(); // it's safe since the vector's length > period
+        let past = &data[data.len() - 1 - period];
+
+        Ok(Self {
+            inner: last.value() - past.value(),
+            epoch_time: last.epoch_time(),
+        })
+    }
+
+    /// Momentum of every bar from sorted values
+    /// NaN for the first `period` bars
+    pub fn columnar(values: &[f64], period: usize) -> Result<Vec<f64>, ToolkitError> {
+        columnar::check(values.len(), period, period + 1)?;
+        let mut result = vec![f64::NAN; period];
+        result.extend(
+            values[period..]
+                .iter()
+                .zip(values.iter())
+                .map(|(last, past)| last - past),
+        );
+        Ok(result)
+    }
+
+    pub fn inner(&self) -> f64 {
+        self.inner
+    }
+}
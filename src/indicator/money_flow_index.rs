@@ -0,0 +1,116 @@
+use super::columnar;
+use crate::types::{
+    data::{BaseData, Candle, CandleColumns},
+    error::ToolkitError,
+};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoneyFlowIndex {
+    inner: f64,
+    epoch_time: u128,
+}
+
+impl BaseData for MoneyFlowIndex {
+    fn value(&self) -> f64 {
+        self.inner
+    }
+
+    fn weight(&self) -> u64 {
+        1
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.epoch_time
+    }
+}
+
+impl MoneyFlowIndex {
+    /// Money Flow Index(MFI)
+    /// period is `data.len() - 1` since the oldest bar is only used as the first typical price
+    /// NaN if there is no money flow
+    pub fn new<T>(data: &[T]) -> Result<Self, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        if data.len() < 2 {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+        let last_epoch_time = data.last().unwrap().epoch_time(); // it's safe since the vector's length > 1
+
+        let mut positive_flow = 0f64;
+        let mut negative_flow = 0f64;
+        for pair in data.windows(2) {
+            let prev_typical_price = Self::typical_price(&pair[0]);
+            let typical_price = Self::typical_price(&pair[1]);
+            let raw_money_flow = typical_price * pair[1].tot_exec_volume() as f64;
+            if typical_price > prev_typical_price {
+                positive_flow += raw_money_flow;
+            } else if typical_price < prev_typical_price {
+                negative_flow += raw_money_flow;
+            }
+        }
+
+        let inner = if positive_flow + negative_flow == 0f64 {
+            f64::NAN
+        } else {
+            positive_flow / (positive_flow + negative_flow) * 100f64
+        };
+        Ok(Self {
+            inner,
+            epoch_time: last_epoch_time,
+        })
+    }
+
+    /// MFI of every bar over the latest `period` price changes, i.e. `new` of the latest `period + 1` bars
+    /// NaN for the first `period` bars, and where there is no money flow
+    pub fn columnar(columns: &CandleColumns, period: usize) -> Result<Vec<f64>, ToolkitError> {
+        columnar::check(columns.len(), period, period + 1)?;
+        let typical_prices: Vec<f64> = columns
+            .high()
+            .iter()
+            .zip(columns.low().iter())
+            .zip(columns.close().iter())
+            .map(|((high, low), close)| (high + low + close) / 3f64)
+            .collect();
+        // bar별 (positive, negative) money flow, 첫 bar는 0
+        let mut positive_flows = vec![0f64; columns.len()];
+        let mut negative_flows = vec![0f64; columns.len()];
+        for i in 1..columns.len() {
+            let raw_money_flow = typical_prices[i] * columns.volume()[i] as f64;
+            if typical_prices[i] > typical_prices[i - 1] {
+                positive_flows[i] = raw_money_flow;
+            } else if typical_prices[i] < typical_prices[i - 1] {
+                negative_flows[i] = raw_money_flow;
+            }
+        }
+        let positive_flows = columnar::rolling_sum(&positive_flows, period);
+        let negative_flows = columnar::rolling_sum(&negative_flows, period);
+        let mut result: Vec<f64> = positive_flows
+            .iter()
+            .zip(negative_flows.iter())
+            .map(|(positive_flow, negative_flow)| {
+                if positive_flow + negative_flow == 0f64 {
+                    f64::NAN
+                } else {
+                    positive_flow / (positive_flow + negative_flow) * 100f64
+                }
+            })
+            .collect();
+        result[..period].fill(f64::NAN);
+        Ok(result)
+    }
+
+    fn typical_price<T>(candle: &T) -> f64
+    where
+        T: Candle,
+    {
+        (candle.high_price() + candle.low_price() + candle.close_price()) / 3f64
+    }
+
+    pub fn inner(&self) -> f64 {
+        self.inner
+    }
+}
@@ -1,6 +1,8 @@
-use crate::types::data::BaseData;
+use super::{Snapshot, Stateful, columnar};
+use crate::types::{data::BaseData, error::ToolkitError};
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MovingAverage {
     Simple(f64),
     Exponential(f64),
@@ -39,9 +41,9 @@ impl MovingAverage {
         let mut data = data.to_vec();
         data.sort_by_key(|k| k.epoch_time());
         let len = data.len() as f64;
-        let k = 2f64 / (len as f64 + 1f64);
+        let k = 2f64 / (len + 1f64);
 
-        let seed = data.iter().map(|d| d.value()).sum::<f64>() / len as f64;
+        let seed = data.iter().map(|d| d.value()).sum::<f64>() / len;
         let mut result = seed;
 
         for curr in data.iter() {
@@ -59,9 +61,49 @@ impl MovingAverage {
         Self::Exponential(new_data.value() * k + prev.inner() * (1f64 - k))
     }
 
+    /// SMA(`period`) of every bar from sorted values
+    /// NaN until the first complete period
+    pub fn simple_columnar(values: &[f64], period: usize) -> Result<Vec<f64>, ToolkitError> {
+        columnar::check(values.len(), period, period)?;
+        Ok(columnar::rolling_sum(values, period)
+            .into_iter()
+            .map(|sum| sum / period as f64)
+            .collect())
+    }
+
+    /// EMA(`period`) of every bar from sorted values
+    /// seeded with the SMA of the first `period` values like `simple` + `exponential_from`
+    /// NaN until the first complete period
+    pub fn exponential_columnar(values: &[f64], period: usize) -> Result<Vec<f64>, ToolkitError> {
+        columnar::check(values.len(), period, period)?;
+        Ok(columnar::exponential(values, period))
+    }
+
     pub fn inner(&self) -> f64 {
         match self {
-            Self::Simple(f) | Self::Exponential(f) => f.clone(),
+            Self::Simple(f) | Self::Exponential(f) => *f,
+        }
+    }
+}
+
+impl Stateful for MovingAverage {
+    const KIND: &'static str = "moving_average";
+    const VERSION: u32 = 1;
+
+    /// values: [0 for simple / 1 for exponential, value]
+    fn snapshot(&self) -> Snapshot {
+        let (variant, value) = match self {
+            Self::Simple(f) => (0f64, *f),
+            Self::Exponential(f) => (1f64, *f),
+        };
+        Snapshot::new::<Self>(None, vec![variant, value])
+    }
+
+    fn restore(snapshot: &Snapshot) -> Result<Self, ToolkitError> {
+        match snapshot.values_of::<Self>(2)? {
+            [variant, value] if *variant == 0f64 => Ok(Self::Simple(*value)),
+            [variant, value] if *variant == 1f64 => Ok(Self::Exponential(*value)),
+            _ => Err(ToolkitError::InvalidData),
         }
     }
 }
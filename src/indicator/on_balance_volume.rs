@@ -0,0 +1,112 @@
+use super::{Snapshot, Stateful};
+use crate::types::{
+    data::{BaseData, Candle, CandleColumns},
+    error::ToolkitError,
+};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OnBalanceVolume {
+    inner: f64,
+    epoch_time: u128,
+}
+
+// make On-Balance Volume usable for MovingAverage
+impl BaseData for OnBalanceVolume {
+    fn value(&self) -> f64 {
+        self.inner
+    }
+
+    fn weight(&self) -> u64 {
+        1
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.epoch_time
+    }
+}
+
+impl OnBalanceVolume {
+    /// On-Balance Volume(OBV)
+    /// accumulated from the volume of the oldest bar, like TA-Lib
+    pub fn new<T>(data: &[T]) -> Result<Self, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        if data.is_empty() {
+            return Err(ToolkitError::EmptyData);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+
+        let mut obv = Self {
+            inner: data[0].tot_exec_volume() as f64,
+            epoch_time: data[0].epoch_time(),
+        };
+        for pair in data.windows(2) {
+            obv = Self::from_prev(&obv, &pair[0], &pair[1]);
+        }
+        Ok(obv)
+    }
+
+    /// On-Balance Volume from previous value
+    pub fn from_prev<T>(prev: &Self, prev_candle: &T, curr: &T) -> Self
+    where
+        T: Candle,
+    {
+        let volume = curr.tot_exec_volume() as f64;
+        let inner = if curr.close_price() > prev_candle.close_price() {
+            prev.inner + volume
+        } else if curr.close_price() < prev_candle.close_price() {
+            prev.inner - volume
+        } else {
+            prev.inner
+        };
+        Self {
+            inner,
+            epoch_time: curr.epoch_time(),
+        }
+    }
+
+    /// OBV of every bar, the volume of the first bar at the first bar like `new`
+    pub fn columnar(columns: &CandleColumns) -> Result<Vec<f64>, ToolkitError> {
+        if columns.is_empty() {
+            return Err(ToolkitError::EmptyData);
+        }
+        let (close, volume) = (columns.close(), columns.volume());
+        let mut result = Vec::with_capacity(columns.len());
+        let mut inner = volume[0] as f64;
+        result.push(inner);
+        for i in 1..columns.len() {
+            if close[i] > close[i - 1] {
+                inner += volume[i] as f64;
+            } else if close[i] < close[i - 1] {
+                inner -= volume[i] as f64;
+            }
+            result.push(inner);
+        }
+        Ok(result)
+    }
+
+    pub fn inner(&self) -> f64 {
+        self.inner
+    }
+}
+
+impl Stateful for OnBalanceVolume {
+    const KIND: &'static str = "on_balance_volume";
+    const VERSION: u32 = 1;
+
+    /// values: [inner]
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::new::<Self>(Some(self.epoch_time), vec![self.inner])
+    }
+
+    fn restore(snapshot: &Snapshot) -> Result<Self, ToolkitError> {
+        let values = snapshot.values_of::<Self>(1)?;
+        Ok(Self {
+            inner: values[0],
+            epoch_time: snapshot.epoch_time()?,
+        })
+    }
+}
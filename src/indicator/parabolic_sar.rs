@@ -0,0 +1,240 @@
+use super::{Snapshot, Stateful, Trend};
+use crate::types::{
+    data::{BaseData, Candle, CandleColumns},
+    error::ToolkitError,
+};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParabolicSar {
+    sar: f64,
+    extreme_point: f64,
+    acceleration: f64,
+    trend: Trend,
+    step: f64,
+    max: f64,
+    /// high prices of the latest 2 bars(latest first), SAR can't penetrate them
+    highs: [f64; 2],
+    /// low prices of the latest 2 bars(latest first), SAR can't penetrate them
+    lows: [f64; 2],
+    epoch_time: u128,
+}
+
+impl BaseData for ParabolicSar {
+    fn value(&self) -> f64 {
+        self.sar
+    }
+
+    fn weight(&self) -> u64 {
+        1
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.epoch_time
+    }
+}
+
+impl ParabolicSar {
+    /// Parabolic SAR of the latest bar
+    /// the classic acceleration step is 0.02 and max is 0.2
+    pub fn new<T>(data: &[T], step: f64, max: f64) -> Result<Self, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        Self::series(data, step, max)?
+            .pop()
+            .ok_or(ToolkitError::DataNotEnough)
+    }
+
+    /// Parabolic SAR of every bar from the 2nd bar
+    /// InvalidData unless `step` and `max` are finite and 0 < `step` <= `max`
+    pub fn series<T>(data: &[T], step: f64, max: f64) -> Result<Vec<Self>, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        Self::check(step, max)?;
+        if data.len() < 2 {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+        let mut psar = Self::seed(&data[0], &data[1], step, max);
+        let mut result = vec![psar];
+        for elem in data.iter().skip(2) {
+            psar = Self::from_prev(&psar, elem);
+            result.push(psar);
+        }
+        Ok(result)
+    }
+
+    /// SAR of every bar, same as `series`
+    /// NaN at the first bar
+    pub fn columnar(
+        columns: &CandleColumns,
+        step: f64,
+        max: f64,
+    ) -> Result<Vec<f64>, ToolkitError> {
+        Self::check(step, max)?;
+        if columns.len() < 2 {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        let mut psar = Self::seed(&columns.candle(0), &columns.candle(1), step, max);
+        let mut result = Vec::with_capacity(columns.len());
+        result.extend([f64::NAN, psar.sar]);
+        for i in 2..columns.len() {
+            psar = Self::from_prev(&psar, &columns.candle(i));
+            result.push(psar.sar);
+        }
+        Ok(result)
+    }
+
+    /// Parabolic SAR of the 2nd bar
+    /// the initial trend is down if the 2nd bar has a minus directional movement, like TA-Lib
+    fn seed<T>(first: &T, second: &T, step: f64, max: f64) -> Self
+    where
+        T: Candle,
+    {
+        let plus_dm = second.high_price() - first.high_price();
+        let minus_dm = first.low_price() - second.low_price();
+        // 첫 bar의 range 끝에서 시작, 2번째 bar를 1번째 bar의 직전 bar로 보고 갱신
+        let (trend, sar, extreme_point) = if minus_dm > 0f64 && plus_dm < minus_dm {
+            (Trend::Down, first.high_price(), second.low_price())
+        } else {
+            (Trend::Up, first.low_price(), second.high_price())
+        };
+        let start = Self {
+            sar,
+            extreme_point,
+            acceleration: step,
+            trend,
+            step,
+            max,
+            highs: [second.high_price(); 2],
+            lows: [second.low_price(); 2],
+            epoch_time: first.epoch_time(),
+        };
+        Self::update(&start, sar, second)
+    }
+
+    /// Parabolic SAR from previous value
+    pub fn from_prev<T>(prev: &Self, curr: &T) -> Self
+    where
+        T: Candle,
+    {
+        let sar = prev.sar + prev.acceleration * (prev.extreme_point - prev.sar);
+        // SAR은 직전 2개 bar의 range를 침범하지 않음
+        let sar = match prev.trend {
+            Trend::Up => sar.min(prev.lows[0]).min(prev.lows[1]),
+            Trend::Down => sar.max(prev.highs[0]).max(prev.highs[1]),
+        };
+        Self::update(prev, sar, curr)
+    }
+
+    /// SAR of `curr` from the SAR projected by `prev`, reversed if `curr` touches it
+    fn update<T>(prev: &Self, sar: f64, curr: &T) -> Self
+    where
+        T: Candle,
+    {
+        let high = curr.high_price();
+        let low = curr.low_price();
+        let mut sar = sar;
+        let mut trend = prev.trend;
+        let mut extreme_point = prev.extreme_point;
+        let mut acceleration = prev.acceleration;
+
+        match prev.trend {
+            Trend::Up => {
+                if low <= sar {
+                    // 추세 반전: 이전 극점이 새로운 SAR, 직전과 현재 bar의 range 밖으로
+                    trend = Trend::Down;
+                    sar = prev.extreme_point.max(prev.highs[0]).max(high);
+                    extreme_point = low;
+                    acceleration = prev.step;
+                } else if high > extreme_point {
+                    extreme_point = high;
+                    acceleration = (acceleration + prev.step).min(prev.max);
+                }
+            }
+            Trend::Down => {
+                if high >= sar {
+                    trend = Trend::Up;
+                    sar = prev.extreme_point.min(prev.lows[0]).min(low);
+                    extreme_point = high;
+                    acceleration = prev.step;
+                } else if low < extreme_point {
+                    extreme_point = low;
+                    acceleration = (acceleration + prev.step).min(prev.max);
+                }
+            }
+        }
+
+        Self {
+            sar,
+            extreme_point,
+            acceleration,
+            trend,
+            step: prev.step,
+            max: prev.max,
+            highs: [high, prev.highs[0]],
+            lows: [low, prev.lows[0]],
+            epoch_time: curr.epoch_time(),
+        }
+    }
+
+    /// InvalidData unless `step` and `max` are finite and 0 < `step` <= `max`
+    fn check(step: f64, max: f64) -> Result<(), ToolkitError> {
+        if !step.is_finite() || !max.is_finite() || step <= 0f64 || max < step {
+            return Err(ToolkitError::InvalidData);
+        }
+        Ok(())
+    }
+
+    /// stop level
+    pub fn sar(&self) -> f64 {
+        self.sar
+    }
+
+    pub fn trend(&self) -> Trend {
+        self.trend
+    }
+}
+
+impl Stateful for ParabolicSar {
+    const KIND: &'static str = "parabolic_sar";
+    const VERSION: u32 = 1;
+
+    /// values: [sar, extreme_point, acceleration, trend(1 for up, -1 for down), step, max, highs[0], highs[1], lows[0], lows[1]]
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::new::<Self>(
+            Some(self.epoch_time),
+            vec![
+                self.sar,
+                self.extreme_point,
+                self.acceleration,
+                self.trend.to_value(),
+                self.step,
+                self.max,
+                self.highs[0],
+                self.highs[1],
+                self.lows[0],
+                self.lows[1],
+            ],
+        )
+    }
+
+    fn restore(snapshot: &Snapshot) -> Result<Self, ToolkitError> {
+        let values = snapshot.values_of::<Self>(10)?;
+        Self::check(values[4], values[5])?;
+        Ok(Self {
+            sar: values[0],
+            extreme_point: values[1],
+            acceleration: values[2],
+            trend: Trend::from_value(values[3])?,
+            step: values[4],
+            max: values[5],
+            highs: [values[6], values[7]],
+            lows: [values[8], values[9]],
+            epoch_time: snapshot.epoch_time()?,
+        })
+    }
+}
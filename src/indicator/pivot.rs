@@ -0,0 +1,261 @@
+use crate::types::{
+    data::{Candle, CandleColumns, ColumnCandle},
+    error::ToolkitError,
+    time::Time,
+};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Pivot {
+    Floor(PivotLevels),
+    Fibonacci(PivotLevels),
+    Camarilla(PivotLevels),
+    Woodie(PivotLevels),
+    DeMark(PivotLevels),
+}
+
+/// support/resistance levels around the pivot
+/// `resistance` and `support` are ordered from the nearest(R1/S1) to the farthest
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PivotLevels {
+    pub pivot: f64,
+    pub resistance: Vec<f64>,
+    pub support: Vec<f64>,
+}
+
+/// pivot levels for a session, computed from the prior session
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SessionPivot {
+    /// start of the session
+    pub epoch_time: u128,
+    pub pivot: Pivot,
+}
+
+/// open, high, low and close of a session
+struct Session {
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+}
+
+impl Session {
+    fn new<T>(data: &[T]) -> Result<Self, ToolkitError>
+    where
+        T: Candle,
+    {
+        let first = data
+            .iter()
+            .min_by_key(|elem| elem.epoch_time())
+            .ok_or(ToolkitError::EmptyData)?;
+        let last = data
+            .iter()
+            .max_by_key(|elem| elem.epoch_time())
+            .ok_or(ToolkitError::EmptyData)?;
+        let mut high = f64::MIN;
+        let mut low = f64::MAX;
+        for elem in data.iter() {
+            high = high.max(elem.high_price());
+            low = low.min(elem.low_price());
+        }
+        Ok(Self {
+            open: first.open_price(),
+            high,
+            low,
+            close: last.close_price(),
+        })
+    }
+
+    fn range(&self) -> f64 {
+        self.high - self.low
+    }
+}
+
+impl Pivot {
+    pub fn inner(&self) -> &PivotLevels {
+        match self {
+            Self::Floor(levels)
+            | Self::Fibonacci(levels)
+            | Self::Camarilla(levels)
+            | Self::Woodie(levels)
+            | Self::DeMark(levels) => levels,
+        }
+    }
+
+    /// Floor(classic) pivot points from the prior session
+    pub fn floor<T>(prev_session: &[T]) -> Result<Self, ToolkitError>
+    where
+        T: Candle,
+    {
+        let session = Session::new(prev_session)?;
+        let pivot = (session.high + session.low + session.close) / 3f64;
+        Ok(Self::Floor(PivotLevels {
+            pivot,
+            resistance: vec![
+                2f64 * pivot - session.low,
+                pivot + session.range(),
+                session.high + 2f64 * (pivot - session.low),
+            ],
+            support: vec![
+                2f64 * pivot - session.high,
+                pivot - session.range(),
+                session.low - 2f64 * (session.high - pivot),
+            ],
+        }))
+    }
+
+    /// Fibonacci pivot points from the prior session
+    pub fn fibonacci<T>(prev_session: &[T]) -> Result<Self, ToolkitError>
+    where
+        T: Candle,
+    {
+        let session = Session::new(prev_session)?;
+        let pivot = (session.high + session.low + session.close) / 3f64;
+        let ratios = [0.382f64, 0.618f64, 1f64];
+        Ok(Self::Fibonacci(PivotLevels {
+            pivot,
+            resistance: ratios
+                .iter()
+                .map(|ratio| pivot + ratio * session.range())
+                .collect(),
+            support: ratios
+                .iter()
+                .map(|ratio| pivot - ratio * session.range())
+                .collect(),
+        }))
+    }
+
+    /// Camarilla pivot points from the prior session
+    pub fn camarilla<T>(prev_session: &[T]) -> Result<Self, ToolkitError>
+    where
+        T: Candle,
+    {
+        let session = Session::new(prev_session)?;
+        let pivot = (session.high + session.low + session.close) / 3f64;
+        let ratios = [1.1f64 / 12f64, 1.1f64 / 6f64, 1.1f64 / 4f64, 1.1f64 / 2f64];
+        Ok(Self::Camarilla(PivotLevels {
+            pivot,
+            resistance: ratios
+                .iter()
+                .map(|ratio| session.close + ratio * session.range())
+                .collect(),
+            support: ratios
+                .iter()
+                .map(|ratio| session.close - ratio * session.range())
+                .collect(),
+        }))
+    }
+
+    /// Woodie pivot points from the prior session
+    /// the close is weighted twice
+    pub fn woodie<T>(prev_session: &[T]) -> Result<Self, ToolkitError>
+    where
+        T: Candle,
+    {
+        let session = Session::new(prev_session)?;
+        let pivot = (session.high + session.low + 2f64 * session.close) / 4f64;
+        Ok(Self::Woodie(PivotLevels {
+            pivot,
+            resistance: vec![2f64 * pivot - session.low, pivot + session.range()],
+            support: vec![2f64 * pivot - session.high, pivot - session.range()],
+        }))
+    }
+
+    /// DeMark pivot points from the prior session
+    /// the weighting depends on whether the session closed above or below its open
+    pub fn demark<T>(prev_session: &[T]) -> Result<Self, ToolkitError>
+    where
+        T: Candle,
+    {
+        let session = Session::new(prev_session)?;
+        let x = if session.close < session.open {
+            session.high + 2f64 * session.low + session.close
+        } else if session.close > session.open {
+            2f64 * session.high + session.low + session.close
+        } else {
+            session.high + session.low + 2f64 * session.close
+        };
+        Ok(Self::DeMark(PivotLevels {
+            pivot: x / 4f64,
+            resistance: vec![x / 2f64 - session.low],
+            support: vec![x / 2f64 - session.high],
+        }))
+    }
+
+    /// group the data into sessions of `length` starting at `offset` from UNIX_EPOCH,
+    /// and compute pivot levels for every session from the prior session with data
+    ///
+    /// e.g. daily sessions starting at 13:30 UTC:
+    /// `Pivot::sessions(&data, Time::from_days(1), Time::from_minutes(13 * 60 + 30), Pivot::floor)`
+    pub fn sessions<T>(
+        data: &[T],
+        length: Time,
+        offset: Time,
+        method: fn(&[T]) -> Result<Self, ToolkitError>,
+    ) -> Result<Vec<SessionPivot>, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+        Self::sorted_sessions(&data, length, offset, method)
+    }
+
+    /// `sessions` of sorted candle columns, `method` gets the rows of the prior session
+    pub fn sessions_columnar<'a>(
+        columns: &CandleColumns<'a>,
+        length: Time,
+        offset: Time,
+        method: fn(&[ColumnCandle<'a>]) -> Result<Self, ToolkitError>,
+    ) -> Result<Vec<SessionPivot>, ToolkitError> {
+        Self::sorted_sessions(&columns.candles(), length, offset, method)
+    }
+
+    fn sorted_sessions<T>(
+        data: &[T],
+        length: Time,
+        offset: Time,
+        method: fn(&[T]) -> Result<Self, ToolkitError>,
+    ) -> Result<Vec<SessionPivot>, ToolkitError>
+    where
+        T: Candle,
+    {
+        if data.is_empty() {
+            return Err(ToolkitError::EmptyData);
+        }
+        let length = length.inner();
+        if length == 0 {
+            return Err(ToolkitError::InvalidData);
+        }
+        let offset = offset.inner() % length;
+
+        let session_start =
+            |epoch_time: u128| epoch_time.saturating_sub((epoch_time + length - offset) % length);
+        // session별 (시작 시각, 첫 bar index)
+        let mut sessions: Vec<(u128, usize)> = vec![];
+        for (i, elem) in data.iter().enumerate() {
+            let start = session_start(elem.epoch_time());
+            if sessions
+                .last()
+                .is_none_or(|(last_start, _)| *last_start != start)
+            {
+                sessions.push((start, i));
+            }
+        }
+        if sessions.len() < 2 {
+            return Err(ToolkitError::DataNotEnough);
+        }
+
+        sessions
+            .windows(2)
+            .map(|pair| {
+                Ok(SessionPivot {
+                    epoch_time: pair[1].0,
+                    pivot: method(&data[pair[0].1..pair[1].1])?,
+                })
+            })
+            .collect()
+    }
+}
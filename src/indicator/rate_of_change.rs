@@ -0,0 +1,78 @@
+use super::columnar;
+use crate::types::{data::BaseData, error::ToolkitError};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RateOfChange {
+    inner: f64,
+    epoch_time: u128,
+}
+
+impl BaseData for RateOfChange {
+    fn value(&self) -> f64 {
+        self.inner
+    }
+
+    fn weight(&self) -> u64 {
+        1
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.epoch_time
+    }
+}
+
+impl RateOfChange {
+    /// Rate of Change(ROC)
+    /// percentage change between the latest value and the value of `period` bars ago
+    /// NaN if the value of `period` bars ago is 0
+    pub fn new<T>(data: &[T], period: usize) -> Result<Self, ToolkitError>
+    where
+        T: BaseData + Clone,
+    {
+        if period == 0 {
+            return Err(ToolkitError::InvalidData);
+        }
+        if data.len() <= period {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+        let last = data.last().unwrap(); // it's safe since the vector's length > period
+        let past = data[data.len() - 1 - period].value();
+        let inner = if past == 0f64 {
+            f64::NAN
+        } else {
+            (last.value() - past) / past * 100f64
+        };
+
+        Ok(Self {
+            inner,
+            epoch_time: last.epoch_time(),
+        })
+    }
+
+    /// ROC of every bar from sorted values
+    /// NaN for the first `period` bars, and where the value of `period` bars ago is 0
+    pub fn columnar(values: &[f64], period: usize) -> Result<Vec<f64>, ToolkitError> {
+        columnar::check(values.len(), period, period + 1)?;
+        let mut result = vec![f64::NAN; period];
+        result.extend(
+            values[period..]
+                .iter()
+                .zip(values.iter())
+                .map(|(last, past)| {
+                    if *past == 0f64 {
+                        f64::NAN
+                    } else {
+                        (last - past) / past * 100f64
+                    }
+                }),
+        );
+        Ok(result)
+    }
+
+    pub fn inner(&self) -> f64 {
+        self.inner
+    }
+}
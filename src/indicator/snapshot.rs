@@ -0,0 +1,125 @@
+use crate::types::error::ToolkitError;
+
+/// versioned state of an incremental indicator
+/// restoring it continues the indicator with identical outputs
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Snapshot {
+    /// indicator the state belongs to
+    pub kind: String,
+    /// layout version of `values`
+    pub version: u32,
+    /// epoch time of the latest bar, None if the indicator doesn't keep it
+    pub epoch_time: Option<u128>,
+    pub values: Vec<f64>,
+}
+
+/// indicator which can be snapshotted and restored
+pub trait Stateful: Sized {
+    const KIND: &'static str;
+    const VERSION: u32;
+
+    fn snapshot(&self) -> Snapshot;
+
+    /// InvalidData if the snapshot is of another indicator or version
+    fn restore(snapshot: &Snapshot) -> Result<Self, ToolkitError>;
+}
+
+impl Snapshot {
+    pub fn new<S>(epoch_time: Option<u128>, values: Vec<f64>) -> Self
+    where
+        S: Stateful,
+    {
+        Self {
+            kind: S::KIND.to_string(),
+            version: S::VERSION,
+            epoch_time,
+            values,
+        }
+    }
+
+    /// values of the snapshot, checked against the kind, the version and the number of values
+    pub fn values_of<S>(&self, len: usize) -> Result<&[f64], ToolkitError>
+    where
+        S: Stateful,
+    {
+        if self.kind != S::KIND || self.version != S::VERSION || self.values.len() != len {
+            return Err(ToolkitError::InvalidData);
+        }
+        Ok(&self.values)
+    }
+
+    /// a count(e.g. a period) stored in `values`, InvalidData unless it's a positive integer
+    pub fn count(value: f64) -> Result<usize, ToolkitError> {
+        if !(value >= 1f64 && value.fract() == 0f64 && value < usize::MAX as f64) {
+            return Err(ToolkitError::InvalidData);
+        }
+        Ok(value as usize)
+    }
+
+    /// epoch time of the snapshot, InvalidData if missing
+    pub fn epoch_time(&self) -> Result<u128, ToolkitError> {
+        self.epoch_time.ok_or(ToolkitError::InvalidData)
+    }
+
+    /// text form persisted without the serde feature, e.g. `macd 1 - 4059000000000000,...`
+    /// values are stored as their bits, so they're restored exactly
+    pub fn encode(&self) -> String {
+        let epoch_time = self
+            .epoch_time
+            .map(|epoch_time| epoch_time.to_string())
+            .unwrap_or_else(|| "-".to_string());
+        let values: Vec<String> = self
+            .values
+            .iter()
+            .map(|value| format!("{:016x}", value.to_bits()))
+            .collect();
+        format!(
+            "{} {} {} {}",
+            self.kind,
+            self.version,
+            epoch_time,
+            values.join(",")
+        )
+    }
+
+    pub fn decode(text: &str) -> Result<Self, ToolkitError> {
+        let mut fields: Vec<&str> = text.trim().split(' ').collect();
+        // trim drops the trailing empty field of a snapshot without values
+        if fields.len() == 3 {
+            fields.push("");
+        }
+        if fields.len() != 4 {
+            return Err(ToolkitError::InvalidData);
+        }
+        let version = fields[1]
+            .parse::<u32>()
+            .map_err(|_| ToolkitError::InvalidData)?;
+        let epoch_time = match fields[2] {
+            "-" => None,
+            epoch_time => Some(
+                epoch_time
+                    .parse::<u128>()
+                    .map_err(|_| ToolkitError::InvalidData)?,
+            ),
+        };
+        let values = if fields[3].is_empty() {
+            vec![]
+        } else {
+            fields[3]
+                .split(',')
+                .map(|value| {
+                    u64::from_str_radix(value, 16)
+                        .map(f64::from_bits)
+                        .map_err(|_| ToolkitError::InvalidData)
+                })
+                .collect::<Result<Vec<f64>, ToolkitError>>()?
+        };
+        Ok(Self {
+            kind: fields[0].to_string(),
+            version,
+            epoch_time,
+            values,
+        })
+    }
+}
@@ -1,28 +1,59 @@
-use super::MovingAverage;
+use super::{MovingAverage, Snapshot, Stateful, columnar};
 use crate::types::{
-    data::{BaseData, Candle},
+    data::{BaseData, Candle, CandleColumns, Point},
     error::ToolkitError,
 };
+use std::collections::VecDeque;
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Stochastic {
     Fast(f64, u128),
     Slow(f64, u128),
 }
 
+/// Stochastic over a rolling window, updated bar by bar
+/// fast %K of the latest `period` bars, slow %D as the SMA of the latest `smoothing` fast %K
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "StochasticWindowFields"))]
+pub struct StochasticWindow {
+    period: usize,
+    smoothing: usize,
+    /// high prices of the latest `period` bars, oldest first
+    highs: VecDeque<f64>,
+    /// low prices of the latest `period` bars, oldest first
+    lows: VecDeque<f64>,
+    /// fast %K of the latest `smoothing` bars, oldest first
+    fasts: VecDeque<f64>,
+    epoch_time: u128,
+}
+
+/// deserialized fields of a `StochasticWindow`, validated before use
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct StochasticWindowFields {
+    period: usize,
+    smoothing: usize,
+    highs: VecDeque<f64>,
+    lows: VecDeque<f64>,
+    fasts: VecDeque<f64>,
+    epoch_time: u128,
+}
+
 impl Stochastic {
     pub fn fast<T>(data: &[T]) -> Result<Self, ToolkitError>
     where
         T: Candle + Clone,
     {
-        if data.len() == 0 {
+        if data.is_empty() {
             return Err(ToolkitError::EmptyData);
         }
         let mut data = data.to_vec();
         data.sort_by_key(|k| k.epoch_time());
         let last_close_price = data.last().unwrap().close_price(); // it's safe since the vector's length > 0
         let mut max_high_price = 0f64;
-        let mut min_low_price = std::f64::MAX;
+        let mut min_low_price = f64::MAX;
         let last_epoch_time = data.last().unwrap().epoch_time(); // it's safe since the vector's length > 0
         for elem in data.iter() {
             max_high_price = max_high_price.max(elem.high_price());
@@ -38,7 +69,7 @@ impl Stochastic {
     where
         T: Candle + Clone,
     {
-        if data.len() == 0 {
+        if data.is_empty() {
             return Err(ToolkitError::EmptyData);
         }
         let mut data = data.to_vec();
@@ -71,12 +102,12 @@ impl Stochastic {
     }
 
     pub fn into_slow(data: &[Self]) -> Result<Self, ToolkitError> {
+        if data.is_empty() {
+            return Err(ToolkitError::EmptyData);
+        }
         if data
             .iter()
-            .filter(|elem| match elem {
-                Self::Slow(_slow, _epoch_time) => true,
-                _ => false,
-            })
+            .filter(|elem| matches!(elem, Self::Slow(_slow, _epoch_time)))
             .count()
             > 0
         {
@@ -109,3 +140,186 @@ impl BaseData for Stochastic {
         }
     }
 }
+
+impl StochasticWindow {
+    /// needs `period + smoothing - 1` bars
+    pub fn new<T>(data: &[T], period: usize, smoothing: usize) -> Result<Self, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        if period == 0 || smoothing == 0 {
+            return Err(ToolkitError::InvalidData);
+        }
+        if data.len() < period + smoothing - 1 {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+
+        let mut window = Self {
+            period,
+            smoothing,
+            highs: data[..period - 1]
+                .iter()
+                .map(|elem| elem.high_price())
+                .collect(),
+            lows: data[..period - 1]
+                .iter()
+                .map(|elem| elem.low_price())
+                .collect(),
+            fasts: VecDeque::with_capacity(smoothing),
+            epoch_time: 0,
+        };
+        for elem in data.iter().skip(period - 1) {
+            window = Self::from_prev(&window, elem);
+        }
+        Ok(window)
+    }
+
+    /// window of restored state, InvalidData unless it's a complete window
+    /// `period` highs and lows, `smoothing` fast %K
+    fn from_parts(
+        period: usize,
+        smoothing: usize,
+        highs: VecDeque<f64>,
+        lows: VecDeque<f64>,
+        fasts: VecDeque<f64>,
+        epoch_time: u128,
+    ) -> Result<Self, ToolkitError> {
+        if period == 0
+            || smoothing == 0
+            || highs.len() != period
+            || lows.len() != period
+            || fasts.len() != smoothing
+        {
+            return Err(ToolkitError::InvalidData);
+        }
+        Ok(Self {
+            period,
+            smoothing,
+            highs,
+            lows,
+            fasts,
+            epoch_time,
+        })
+    }
+
+    /// Stochastic from previous window
+    pub fn from_prev<T>(prev: &Self, curr: &T) -> Self
+    where
+        T: Candle,
+    {
+        let mut window = prev.clone();
+        window.highs.push_back(curr.high_price());
+        window.lows.push_back(curr.low_price());
+        if window.highs.len() > window.period {
+            window.highs.pop_front();
+            window.lows.pop_front();
+        }
+        let max_high_price = window.highs.iter().fold(0f64, |max, high| max.max(*high));
+        let min_low_price = window.lows.iter().fold(f64::MAX, |min, low| min.min(*low));
+        window.fasts.push_back(
+            (curr.close_price() - min_low_price) / (max_high_price - min_low_price) * 100f64,
+        );
+        if window.fasts.len() > window.smoothing {
+            window.fasts.pop_front();
+        }
+        window.epoch_time = curr.epoch_time();
+        window
+    }
+
+    /// (fast %K, slow %D) of every bar, same as the window updated bar by bar
+    /// fast %K is NaN until the first complete `period`, slow %D until `period + smoothing - 1` bars
+    pub fn columnar(
+        columns: &CandleColumns,
+        period: usize,
+        smoothing: usize,
+    ) -> Result<(Vec<f64>, Vec<f64>), ToolkitError> {
+        if smoothing == 0 {
+            return Err(ToolkitError::InvalidData);
+        }
+        columnar::check(columns.len(), period, period + smoothing - 1)?;
+        let highs = columnar::rolling_max(columns.high(), period);
+        let lows = columnar::rolling_min(columns.low(), period);
+        let fasts: Vec<f64> = columns
+            .close()
+            .iter()
+            .zip(highs.iter().zip(lows.iter()))
+            .map(|(close, (high, low))| (close - low) / (high - low) * 100f64)
+            .collect();
+        let mut slows = vec![f64::NAN; period - 1];
+        slows.extend(
+            columnar::rolling_sum(&fasts[period - 1..], smoothing)
+                .into_iter()
+                .map(|sum| sum / smoothing as f64),
+        );
+        Ok((fasts, slows))
+    }
+
+    /// fast %K
+    pub fn fast(&self) -> Stochastic {
+        // it's safe since a fast %K is pushed for every bar
+        Stochastic::Fast(*self.fasts.back().unwrap(), self.epoch_time)
+    }
+
+    /// slow %D
+    pub fn slow(&self) -> Stochastic {
+        let fasts: Vec<Point> = self
+            .fasts
+            .iter()
+            .map(|fast| Point::new(*fast, self.epoch_time))
+            .collect();
+        Stochastic::Slow(MovingAverage::simple(&fasts).inner(), self.epoch_time)
+    }
+}
+
+impl Stateful for StochasticWindow {
+    const KIND: &'static str = "stochastic_window";
+    const VERSION: u32 = 1;
+
+    /// values: [period, smoothing, highs.., lows.., fasts..]
+    fn snapshot(&self) -> Snapshot {
+        let mut values = vec![self.period as f64, self.smoothing as f64];
+        values.extend(self.highs.iter());
+        values.extend(self.lows.iter());
+        values.extend(self.fasts.iter());
+        Snapshot::new::<Self>(Some(self.epoch_time), values)
+    }
+
+    fn restore(snapshot: &Snapshot) -> Result<Self, ToolkitError> {
+        let (period, smoothing) = match snapshot.values.as_slice() {
+            [period, smoothing, ..] => (Snapshot::count(*period)?, Snapshot::count(*smoothing)?),
+            _ => return Err(ToolkitError::InvalidData),
+        };
+        let len = period
+            .checked_mul(2)
+            .and_then(|len| len.checked_add(smoothing))
+            .and_then(|len| len.checked_add(2))
+            .ok_or(ToolkitError::InvalidData)?;
+        let values = snapshot.values_of::<Self>(len)?;
+        Self::from_parts(
+            period,
+            smoothing,
+            values[2..2 + period].iter().copied().collect(),
+            values[2 + period..2 + 2 * period].iter().copied().collect(),
+            values[2 + 2 * period..].iter().copied().collect(),
+            snapshot.epoch_time()?,
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<StochasticWindowFields> for StochasticWindow {
+    type Error = ToolkitError;
+
+    fn try_from(fields: StochasticWindowFields) -> Result<Self, ToolkitError> {
+        Self::from_parts(
+            fields.period,
+            fields.smoothing,
+            fields.highs,
+            fields.lows,
+            fields.fasts,
+            fields.epoch_time,
+        )
+    }
+}
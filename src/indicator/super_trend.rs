@@ -0,0 +1,235 @@
+use super::{AverageTrueRange, Snapshot, Stateful, Trend};
+use crate::types::{
+    data::{BaseData, Candle, CandleColumns, ColumnCandle},
+    error::ToolkitError,
+};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "SuperTrendFields"))]
+pub struct SuperTrend {
+    atr: AverageTrueRange,
+    upper: f64,
+    lower: f64,
+    close: f64,
+    trend: Trend,
+    period: usize,
+    multiplier: f64,
+}
+
+/// deserialized fields of a `SuperTrend`, validated before use
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SuperTrendFields {
+    atr: AverageTrueRange,
+    upper: f64,
+    lower: f64,
+    close: f64,
+    trend: Trend,
+    period: usize,
+    multiplier: f64,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SuperTrendFields> for SuperTrend {
+    type Error = ToolkitError;
+
+    /// InvalidData for a zero ATR period
+    fn try_from(fields: SuperTrendFields) -> Result<Self, ToolkitError> {
+        if fields.period == 0 {
+            return Err(ToolkitError::InvalidData);
+        }
+        Ok(Self {
+            atr: fields.atr,
+            upper: fields.upper,
+            lower: fields.lower,
+            close: fields.close,
+            trend: fields.trend,
+            period: fields.period,
+            multiplier: fields.multiplier,
+        })
+    }
+}
+
+impl BaseData for SuperTrend {
+    fn value(&self) -> f64 {
+        self.stop()
+    }
+
+    fn weight(&self) -> u64 {
+        1
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.atr.epoch_time()
+    }
+}
+
+impl SuperTrend {
+    /// SuperTrend of the latest bar
+    /// the classic ATR period is 10 and multiplier is 3
+    pub fn new<T>(data: &[T], period: usize, multiplier: f64) -> Result<Self, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        Self::series(data, period, multiplier)?
+            .pop()
+            .ok_or(ToolkitError::DataNotEnough)
+    }
+
+    /// SuperTrend of every bar from the first complete ATR period
+    /// InvalidData unless `multiplier` is finite and positive
+    pub fn series<T>(data: &[T], period: usize, multiplier: f64) -> Result<Vec<Self>, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        if !multiplier.is_finite() || multiplier <= 0f64 {
+            return Err(ToolkitError::InvalidData);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+        let atr = AverageTrueRange::new(&data[0..(period + 1).min(data.len())], period)?;
+        let mut super_trend = Self::seed(atr, &data[period], period, multiplier);
+        let mut result = vec![super_trend];
+        for elem in data.iter().skip(period + 1) {
+            super_trend = Self::from_prev(&super_trend, elem);
+            result.push(super_trend);
+        }
+        Ok(result)
+    }
+
+    /// stop level of every bar, same as `series`
+    /// NaN for the first `period` bars
+    pub fn columnar(
+        columns: &CandleColumns,
+        period: usize,
+        multiplier: f64,
+    ) -> Result<Vec<f64>, ToolkitError> {
+        if !multiplier.is_finite() || multiplier <= 0f64 {
+            return Err(ToolkitError::InvalidData);
+        }
+        let seed: Vec<ColumnCandle> = (0..(period + 1).min(columns.len()))
+            .map(|i| columns.candle(i))
+            .collect();
+        let atr = AverageTrueRange::new(&seed, period)?;
+        let mut super_trend = Self::seed(atr, &columns.candle(period), period, multiplier);
+        let mut result = vec![f64::NAN; period];
+        result.push(super_trend.stop());
+        for i in period + 1..columns.len() {
+            super_trend = Self::from_prev(&super_trend, &columns.candle(i));
+            result.push(super_trend.stop());
+        }
+        Ok(result)
+    }
+
+    /// SuperTrend of the first bar with ATR
+    fn seed<T>(atr: AverageTrueRange, first: &T, period: usize, multiplier: f64) -> Self
+    where
+        T: Candle,
+    {
+        let mid = (first.high_price() + first.low_price()) / 2f64;
+        Self {
+            atr,
+            upper: mid + multiplier * atr.inner(),
+            lower: mid - multiplier * atr.inner(),
+            close: first.close_price(),
+            trend: if first.close_price() >= mid {
+                Trend::Up
+            } else {
+                Trend::Down
+            },
+            period,
+            multiplier,
+        }
+    }
+
+    /// SuperTrend from previous value
+    pub fn from_prev<T>(prev: &Self, curr: &T) -> Self
+    where
+        T: Candle,
+    {
+        let atr = AverageTrueRange::from_prev(prev.period, &prev.atr, curr);
+        let mid = (curr.high_price() + curr.low_price()) / 2f64;
+        let basic_upper = mid + prev.multiplier * atr.inner();
+        let basic_lower = mid - prev.multiplier * atr.inner();
+
+        // band는 추세 방향으로만 조여짐, 이전 종가가 band를 뚫었으면 초기화
+        let upper = if basic_upper < prev.upper || prev.close > prev.upper {
+            basic_upper
+        } else {
+            prev.upper
+        };
+        let lower = if basic_lower > prev.lower || prev.close < prev.lower {
+            basic_lower
+        } else {
+            prev.lower
+        };
+        let close = curr.close_price();
+        let trend = match prev.trend {
+            Trend::Down if close > upper => Trend::Up,
+            Trend::Up if close < lower => Trend::Down,
+            trend => trend,
+        };
+
+        Self {
+            atr,
+            upper,
+            lower,
+            close,
+            trend,
+            period: prev.period,
+            multiplier: prev.multiplier,
+        }
+    }
+
+    /// stop level
+    /// lower band in up trend, upper band in down trend
+    pub fn stop(&self) -> f64 {
+        match self.trend {
+            Trend::Up => self.lower,
+            Trend::Down => self.upper,
+        }
+    }
+
+    pub fn trend(&self) -> Trend {
+        self.trend
+    }
+
+    pub fn atr(&self) -> AverageTrueRange {
+        self.atr
+    }
+}
+
+impl Stateful for SuperTrend {
+    const KIND: &'static str = "super_trend";
+    const VERSION: u32 = 1;
+
+    /// values: [ATR values, upper, lower, close, trend(1 for up, -1 for down), period, multiplier]
+    fn snapshot(&self) -> Snapshot {
+        let atr = self.atr.snapshot();
+        let mut values = atr.values;
+        values.extend([
+            self.upper,
+            self.lower,
+            self.close,
+            self.trend.to_value(),
+            self.period as f64,
+            self.multiplier,
+        ]);
+        Snapshot::new::<Self>(atr.epoch_time, values)
+    }
+
+    fn restore(snapshot: &Snapshot) -> Result<Self, ToolkitError> {
+        let values = snapshot.values_of::<Self>(8)?;
+        let atr = Snapshot::new::<AverageTrueRange>(snapshot.epoch_time, values[..2].to_vec());
+        Ok(Self {
+            atr: AverageTrueRange::restore(&atr)?,
+            upper: values[2],
+            lower: values[3],
+            close: values[4],
+            trend: Trend::from_value(values[5])?,
+            period: Snapshot::count(values[6])?,
+            multiplier: values[7],
+        })
+    }
+}
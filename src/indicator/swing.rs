@@ -0,0 +1,126 @@
+use crate::types::{
+    data::{BaseData, Candle, CandleColumns, Point},
+    error::ToolkitError,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SwingKind {
+    High,
+    Low,
+}
+
+/// confirmed swing point(pivot)
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SwingPoint {
+    pub kind: SwingKind,
+    pub point: Point,
+    /// epoch time of the bar which confirmed the swing point
+    pub confirmed_at: u128,
+    /// bars between the swing point and its confirmation
+    pub lag: usize,
+}
+
+impl SwingPoint {
+    /// swing highs/lows of candles(N-bar fractal)
+    /// a swing high is a high price higher than `left` bars before and not lower than `right` bars after,
+    /// so it's confirmed `right` bars later
+    pub fn detect<T>(data: &[T], left: usize, right: usize) -> Result<Vec<Self>, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+        let highs: Vec<f64> = data.iter().map(|elem| elem.high_price()).collect();
+        let lows: Vec<f64> = data.iter().map(|elem| elem.low_price()).collect();
+        let epoch_times: Vec<u128> = data.iter().map(|elem| elem.epoch_time()).collect();
+        Self::fractal(&highs, &lows, &epoch_times, left, right)
+    }
+
+    /// swing highs/lows of a value series(N-bar fractal)
+    pub fn detect_values<T>(
+        data: &[T],
+        left: usize,
+        right: usize,
+    ) -> Result<Vec<Self>, ToolkitError>
+    where
+        T: BaseData + Clone,
+    {
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+        let values: Vec<f64> = data.iter().map(|elem| elem.value()).collect();
+        let epoch_times: Vec<u128> = data.iter().map(|elem| elem.epoch_time()).collect();
+        Self::fractal(&values, &values, &epoch_times, left, right)
+    }
+
+    /// `detect` of sorted candle columns
+    pub fn detect_columnar(
+        columns: &CandleColumns,
+        left: usize,
+        right: usize,
+    ) -> Result<Vec<Self>, ToolkitError> {
+        Self::fractal(
+            columns.high(),
+            columns.low(),
+            columns.epoch_times(),
+            left,
+            right,
+        )
+    }
+
+    /// `detect_values` of sorted values and their epoch times
+    pub fn detect_values_columnar(
+        values: &[f64],
+        epoch_times: &[u128],
+        left: usize,
+        right: usize,
+    ) -> Result<Vec<Self>, ToolkitError> {
+        if values.len() != epoch_times.len() {
+            return Err(ToolkitError::InvalidData);
+        }
+        Self::fractal(values, values, epoch_times, left, right)
+    }
+
+    fn fractal(
+        highs: &[f64],
+        lows: &[f64],
+        epoch_times: &[u128],
+        left: usize,
+        right: usize,
+    ) -> Result<Vec<Self>, ToolkitError> {
+        if highs.is_empty() {
+            return Err(ToolkitError::EmptyData);
+        }
+        if left == 0 || right == 0 {
+            return Err(ToolkitError::InvalidData);
+        }
+        let mut swings = vec![];
+        for i in left..highs.len().saturating_sub(right) {
+            let confirmed_at = epoch_times[i + right];
+            let high = highs[i];
+            if highs[i - left..i].iter().all(|elem| *elem < high)
+                && highs[i + 1..=i + right].iter().all(|elem| *elem <= high)
+            {
+                swings.push(Self {
+                    kind: SwingKind::High,
+                    point: Point::new(high, epoch_times[i]),
+                    confirmed_at,
+                    lag: right,
+                });
+            }
+            let low = lows[i];
+            if lows[i - left..i].iter().all(|elem| *elem > low)
+                && lows[i + 1..=i + right].iter().all(|elem| *elem >= low)
+            {
+                swings.push(Self {
+                    kind: SwingKind::Low,
+                    point: Point::new(low, epoch_times[i]),
+                    confirmed_at,
+                    lag: right,
+                });
+            }
+        }
+        Ok(swings)
+    }
+}
@@ -0,0 +1,29 @@
+use crate::types::error::ToolkitError;
+
+/// direction of a trend-following indicator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Trend {
+    Up,
+    Down,
+}
+
+impl Trend {
+    /// 1 for up, -1 for down, used by snapshots
+    pub(crate) fn to_value(self) -> f64 {
+        match self {
+            Self::Up => 1f64,
+            Self::Down => -1f64,
+        }
+    }
+
+    pub(crate) fn from_value(value: f64) -> Result<Self, ToolkitError> {
+        if value == 1f64 {
+            Ok(Self::Up)
+        } else if value == -1f64 {
+            Ok(Self::Down)
+        } else {
+            Err(ToolkitError::InvalidData)
+        }
+    }
+}
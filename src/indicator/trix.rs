@@ -0,0 +1,168 @@
+use super::{MovingAverage, Snapshot, Stateful, columnar};
+use crate::types::{data::BaseData, error::ToolkitError};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trix {
+    ema_1: f64,
+    ema_2: f64,
+    ema_3: f64,
+    prev_ema_3: f64,
+    epoch_time: u128,
+}
+
+// EMA 값을 BaseData로 wrapping하여 MovingAverage에 활용
+#[derive(Debug, Clone, Copy)]
+struct TrixPoint(f64, u128);
+impl BaseData for TrixPoint {
+    fn value(&self) -> f64 {
+        self.0
+    }
+    fn weight(&self) -> u64 {
+        1
+    }
+    fn epoch_time(&self) -> u128 {
+        self.1
+    }
+}
+
+impl BaseData for Trix {
+    fn value(&self) -> f64 {
+        self.inner()
+    }
+
+    fn weight(&self) -> u64 {
+        1
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.epoch_time
+    }
+}
+
+impl Trix {
+    /// TRIX
+    /// 1-bar rate of change of the triple smoothed EMA(`period`)
+    /// requires at least `3 * (period - 1) + 2` data
+    pub fn new<T>(data: &[T], period: usize) -> Result<Self, ToolkitError>
+    where
+        T: BaseData + Clone,
+    {
+        if period == 0 {
+            return Err(ToolkitError::InvalidData);
+        }
+        if data.len() < 3 * (period - 1) + 2 {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+        let points: Vec<TrixPoint> = data
+            .iter()
+            .map(|elem| TrixPoint(elem.value(), elem.epoch_time()))
+            .collect();
+
+        // 각 EMA는 첫 period개의 SMA로 seed → 이후 rolling
+        let ema_series = |points: &[TrixPoint]| -> Vec<TrixPoint> {
+            let mut ema = MovingAverage::simple(&points[0..period]);
+            let mut result = vec![TrixPoint(ema.inner(), points[period - 1].epoch_time())];
+            for elem in points.iter().skip(period) {
+                ema = MovingAverage::exponential_from(period, &ema, elem);
+                result.push(TrixPoint(ema.inner(), elem.epoch_time()));
+            }
+            result
+        };
+        let ema_1 = ema_series(&points);
+        let ema_2 = ema_series(&ema_1);
+        let ema_3 = ema_series(&ema_2);
+
+        let n = ema_3.len();
+        Ok(Self {
+            ema_1: ema_1.last().unwrap().value(), // it's safe since the series are not empty
+            ema_2: ema_2.last().unwrap().value(),
+            ema_3: ema_3[n - 1].value(),
+            prev_ema_3: ema_3[n - 2].value(),
+            epoch_time: ema_3[n - 1].epoch_time(),
+        })
+    }
+
+    /// TRIX from previous value
+    pub fn from_prev<T>(period: usize, prev: &Self, new_data: &T) -> Self
+    where
+        T: BaseData + Clone,
+    {
+        let epoch_time = new_data.epoch_time();
+        let ema_1 = MovingAverage::exponential_from(
+            period,
+            &MovingAverage::Exponential(prev.ema_1),
+            new_data,
+        );
+        let ema_2 = MovingAverage::exponential_from(
+            period,
+            &MovingAverage::Exponential(prev.ema_2),
+            &TrixPoint(ema_1.inner(), epoch_time),
+        );
+        let ema_3 = MovingAverage::exponential_from(
+            period,
+            &MovingAverage::Exponential(prev.ema_3),
+            &TrixPoint(ema_2.inner(), epoch_time),
+        );
+        Self {
+            ema_1: ema_1.inner(),
+            ema_2: ema_2.inner(),
+            ema_3: ema_3.inner(),
+            prev_ema_3: prev.ema_3,
+            epoch_time,
+        }
+    }
+
+    /// TRIX of every bar from sorted values
+    /// NaN for the first `3 * (period - 1) + 1` bars, and where the previous triple EMA is 0
+    pub fn columnar(values: &[f64], period: usize) -> Result<Vec<f64>, ToolkitError> {
+        columnar::check(values.len(), period, 3 * (period.max(1) - 1) + 2)?;
+        let warmup = period - 1;
+        let ema_1 = columnar::exponential(values, period);
+        let ema_2 = columnar::exponential(&ema_1[warmup..], period);
+        let ema_3 = columnar::exponential(&ema_2[warmup..], period);
+        let mut result = vec![f64::NAN; 2 * warmup + 1];
+        result.extend(ema_3.windows(2).map(|pair| Self::rate(pair[0], pair[1])));
+        Ok(result)
+    }
+
+    /// NaN if the previous triple EMA is 0
+    pub fn inner(&self) -> f64 {
+        Self::rate(self.prev_ema_3, self.ema_3)
+    }
+
+    /// percentage change of the triple EMA, NaN instead of dividing by 0
+    fn rate(prev: f64, curr: f64) -> f64 {
+        if prev == 0f64 {
+            f64::NAN
+        } else {
+            (curr - prev) / prev * 100f64
+        }
+    }
+}
+
+impl Stateful for Trix {
+    const KIND: &'static str = "trix";
+    const VERSION: u32 = 1;
+
+    /// values: [ema_1, ema_2, ema_3, prev_ema_3]
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::new::<Self>(
+            Some(self.epoch_time),
+            vec![self.ema_1, self.ema_2, self.ema_3, self.prev_ema_3],
+        )
+    }
+
+    fn restore(snapshot: &Snapshot) -> Result<Self, ToolkitError> {
+        let values = snapshot.values_of::<Self>(4)?;
+        Ok(Self {
+            ema_1: values[0],
+            ema_2: values[1],
+            ema_3: values[2],
+            prev_ema_3: values[3],
+            epoch_time: snapshot.epoch_time()?,
+        })
+    }
+}
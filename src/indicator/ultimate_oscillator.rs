@@ -0,0 +1,121 @@
+use super::columnar;
+use crate::types::{
+    data::{BaseData, Candle, CandleColumns},
+    error::ToolkitError,
+};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UltimateOscillator {
+    inner: f64,
+    epoch_time: u128,
+}
+
+impl BaseData for UltimateOscillator {
+    fn value(&self) -> f64 {
+        self.inner
+    }
+
+    fn weight(&self) -> u64 {
+        1
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.epoch_time
+    }
+}
+
+impl UltimateOscillator {
+    /// Ultimate Oscillator
+    /// weighted(4:2:1) average of buying pressure / true range over `short`, `mid` and `long` bars
+    /// the classic periods are 7, 14 and 28
+    /// NaN if the true range of a period is 0
+    pub fn new<T>(data: &[T], short: usize, mid: usize, long: usize) -> Result<Self, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        if short == 0 || short > mid || mid > long {
+            return Err(ToolkitError::InvalidData);
+        }
+        if data.len() <= long {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+
+        // 최신 bar부터 역순으로 (buying pressure, true range) 수집
+        let pressures: Vec<(f64, f64)> = data
+            .windows(2)
+            .rev()
+            .take(long)
+            .map(|pair| {
+                let prev_close = pair[0].close_price();
+                let true_low = pair[1].low_price().min(prev_close);
+                let true_high = pair[1].high_price().max(prev_close);
+                (pair[1].close_price() - true_low, true_high - true_low)
+            })
+            .collect();
+        let average = |period: usize| -> f64 {
+            let (buying_pressure, true_range) = pressures
+                .iter()
+                .take(period)
+                .fold((0f64, 0f64), |acc, elem| (acc.0 + elem.0, acc.1 + elem.1));
+            if true_range == 0f64 {
+                f64::NAN
+            } else {
+                buying_pressure / true_range
+            }
+        };
+
+        Ok(Self {
+            inner: (4f64 * average(short) + 2f64 * average(mid) + average(long)) / 7f64 * 100f64,
+            epoch_time: data.last().unwrap().epoch_time(), // it's safe since the vector's length > long
+        })
+    }
+
+    /// Ultimate Oscillator of every bar
+    /// NaN for the first `long` bars, and where the true range of a period is 0
+    pub fn columnar(
+        columns: &CandleColumns,
+        short: usize,
+        mid: usize,
+        long: usize,
+    ) -> Result<Vec<f64>, ToolkitError> {
+        if short > mid || mid > long {
+            return Err(ToolkitError::InvalidData);
+        }
+        columnar::check(columns.len(), short, long + 1)?;
+        let (high, low, close) = (columns.high(), columns.low(), columns.close());
+        // bar별 (buying pressure, true range), 첫 bar는 0
+        let mut buying_pressures = vec![0f64; columns.len()];
+        let mut true_ranges = vec![0f64; columns.len()];
+        for i in 1..columns.len() {
+            let true_low = low[i].min(close[i - 1]);
+            buying_pressures[i] = close[i] - true_low;
+            true_ranges[i] = high[i].max(close[i - 1]) - true_low;
+        }
+        let average = |period: usize| -> Vec<f64> {
+            columnar::rolling_sum(&buying_pressures, period)
+                .iter()
+                .zip(columnar::rolling_sum(&true_ranges, period).iter())
+                .map(|(buying_pressure, true_range)| {
+                    if *true_range == 0f64 {
+                        f64::NAN
+                    } else {
+                        buying_pressure / true_range
+                    }
+                })
+                .collect()
+        };
+        let (short, mid, long_average) = (average(short), average(mid), average(long));
+        let mut result: Vec<f64> = (0..columns.len())
+            .map(|i| (4f64 * short[i] + 2f64 * mid[i] + long_average[i]) / 7f64 * 100f64)
+            .collect();
+        result[..long].fill(f64::NAN);
+        Ok(result)
+    }
+
+    pub fn inner(&self) -> f64 {
+        self.inner
+    }
+}
@@ -0,0 +1,343 @@
+use super::columnar;
+use crate::stats::RollingStats;
+use crate::types::{
+    data::{Candle, CandleColumns},
+    error::ToolkitError,
+    time::Time,
+};
+
+/// annualised historical volatility
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Volatility {
+    CloseToClose(f64),
+    Parkinson(f64),
+    GarmanKlass(f64),
+    RogersSatchell(f64),
+    YangZhang(f64),
+}
+
+impl Volatility {
+    pub fn inner(&self) -> f64 {
+        match self {
+            Self::CloseToClose(f)
+            | Self::Parkinson(f)
+            | Self::GarmanKlass(f)
+            | Self::RogersSatchell(f)
+            | Self::YangZhang(f) => *f,
+        }
+    }
+
+    /// Close-to-Close volatility
+    /// sample standard deviation of log returns
+    pub fn close_to_close<T>(data: &[T], year: Time) -> Result<Self, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        let data = Self::prepare(data, 3)?;
+        let stats = data
+            .windows(2)
+            .fold(RollingStats::default(), |stats, pair| {
+                stats.add((pair[1].close_price() / pair[0].close_price()).ln())
+            });
+        Ok(Self::CloseToClose(Self::annualise(
+            &data,
+            stats.sample_variance(),
+            year,
+        )?))
+    }
+
+    /// Parkinson volatility
+    /// uses high/low range only
+    pub fn parkinson<T>(data: &[T], year: Time) -> Result<Self, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        let data = Self::prepare(data, 2)?;
+        let variance = data
+            .iter()
+            .map(|elem| (elem.high_price() / elem.low_price()).ln().powi(2))
+            .sum::<f64>()
+            / (4f64 * 2f64.ln() * data.len() as f64);
+        Ok(Self::Parkinson(Self::annualise(&data, variance, year)?))
+    }
+
+    /// Garman-Klass volatility
+    /// uses open/high/low/close, assumes no drift and no opening jump
+    pub fn garman_klass<T>(data: &[T], year: Time) -> Result<Self, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        let data = Self::prepare(data, 2)?;
+        let variance = data
+            .iter()
+            .map(|elem| {
+                0.5f64 * (elem.high_price() / elem.low_price()).ln().powi(2)
+                    - (2f64 * 2f64.ln() - 1f64)
+                        * (elem.close_price() / elem.open_price()).ln().powi(2)
+            })
+            .sum::<f64>()
+            / data.len() as f64;
+        Ok(Self::GarmanKlass(Self::annualise(
+            &data,
+            variance.max(0f64),
+            year,
+        )?))
+    }
+
+    /// Rogers-Satchell volatility
+    /// uses open/high/low/close, independent of drift
+    pub fn rogers_satchell<T>(data: &[T], year: Time) -> Result<Self, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        let data = Self::prepare(data, 2)?;
+        let variance = data.iter().map(Self::rogers_satchell_term).sum::<f64>() / data.len() as f64;
+        Ok(Self::RogersSatchell(Self::annualise(
+            &data, variance, year,
+        )?))
+    }
+
+    /// Yang-Zhang volatility
+    /// combines overnight(close to open), open-to-close and Rogers-Satchell variances,
+    /// independent of drift and handles opening jumps
+    pub fn yang_zhang<T>(data: &[T], year: Time) -> Result<Self, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        let data = Self::prepare(data, 3)?;
+        let mut overnight = RollingStats::default();
+        let mut open_to_close = RollingStats::default();
+        let mut rogers_satchell = 0f64;
+        for pair in data.windows(2) {
+            overnight = overnight.add((pair[1].open_price() / pair[0].close_price()).ln());
+            open_to_close = open_to_close.add((pair[1].close_price() / pair[1].open_price()).ln());
+            rogers_satchell += Self::rogers_satchell_term(&pair[1]);
+        }
+        let n = (data.len() - 1) as f64;
+        let k = 0.34f64 / (1.34f64 + (n + 1f64) / (n - 1f64));
+        let variance = overnight.sample_variance()
+            + k * open_to_close.sample_variance()
+            + (1f64 - k) * rogers_satchell / n;
+        Ok(Self::YangZhang(Self::annualise(&data, variance, year)?))
+    }
+
+    /// Close-to-Close volatility of every bar over the latest `period` bars
+    /// bars per year are inferred once from the bar interval of the whole columns
+    /// NaN until the first complete period
+    pub fn close_to_close_columnar(
+        columns: &CandleColumns,
+        period: usize,
+        year: Time,
+    ) -> Result<Vec<f64>, ToolkitError> {
+        let bars_per_year = Self::prepare_columns(columns, period, 3, year)?;
+        let close = columns.close();
+        let returns: Vec<f64> = close
+            .windows(2)
+            .map(|pair| (pair[1] / pair[0]).ln())
+            .collect();
+        // window의 period개 bar는 period - 1개 수익률
+        let mut result = vec![f64::NAN; columns.len()];
+        let mut stats = RollingStats::default();
+        for (i, value) in returns.iter().enumerate() {
+            stats = stats.add(*value);
+            if i + 1 >= period {
+                stats = stats.remove(returns[i + 1 - period]);
+            }
+            if i + 2 >= period {
+                result[i + 1] = (stats.sample_variance() * bars_per_year).sqrt();
+            }
+        }
+        Ok(result)
+    }
+
+    /// Parkinson volatility of every bar over the latest `period` bars
+    pub fn parkinson_columnar(
+        columns: &CandleColumns,
+        period: usize,
+        year: Time,
+    ) -> Result<Vec<f64>, ToolkitError> {
+        let bars_per_year = Self::prepare_columns(columns, period, 2, year)?;
+        let terms: Vec<f64> = columns
+            .high()
+            .iter()
+            .zip(columns.low().iter())
+            .map(|(high, low)| (high / low).ln().powi(2))
+            .collect();
+        Ok(Self::annualise_sums(
+            &terms,
+            period,
+            4f64 * 2f64.ln() * period as f64,
+            bars_per_year,
+        ))
+    }
+
+    /// Garman-Klass volatility of every bar over the latest `period` bars
+    pub fn garman_klass_columnar(
+        columns: &CandleColumns,
+        period: usize,
+        year: Time,
+    ) -> Result<Vec<f64>, ToolkitError> {
+        let bars_per_year = Self::prepare_columns(columns, period, 2, year)?;
+        let terms: Vec<f64> = (0..columns.len())
+            .map(|i| {
+                0.5f64 * (columns.high()[i] / columns.low()[i]).ln().powi(2)
+                    - (2f64 * 2f64.ln() - 1f64)
+                        * (columns.close()[i] / columns.open()[i]).ln().powi(2)
+            })
+            .collect();
+        Ok(Self::annualise_sums(
+            &terms,
+            period,
+            period as f64,
+            bars_per_year,
+        ))
+    }
+
+    /// Rogers-Satchell volatility of every bar over the latest `period` bars
+    pub fn rogers_satchell_columnar(
+        columns: &CandleColumns,
+        period: usize,
+        year: Time,
+    ) -> Result<Vec<f64>, ToolkitError> {
+        let bars_per_year = Self::prepare_columns(columns, period, 2, year)?;
+        let terms: Vec<f64> = (0..columns.len())
+            .map(|i| Self::rogers_satchell_term(&columns.candle(i)))
+            .collect();
+        Ok(Self::annualise_sums(
+            &terms,
+            period,
+            period as f64,
+            bars_per_year,
+        ))
+    }
+
+    /// Yang-Zhang volatility of every bar over the latest `period` bars
+    pub fn yang_zhang_columnar(
+        columns: &CandleColumns,
+        period: usize,
+        year: Time,
+    ) -> Result<Vec<f64>, ToolkitError> {
+        let bars_per_year = Self::prepare_columns(columns, period, 3, year)?;
+        let (open, close) = (columns.open(), columns.close());
+        // bar i(≥ 1)별 (overnight, open-to-close, Rogers-Satchell)
+        let terms: Vec<(f64, f64, f64)> = (1..columns.len())
+            .map(|i| {
+                (
+                    (open[i] / close[i - 1]).ln(),
+                    (close[i] / open[i]).ln(),
+                    Self::rogers_satchell_term(&columns.candle(i)),
+                )
+            })
+            .collect();
+        let n = (period - 1) as f64;
+        let k = 0.34f64 / (1.34f64 + (n + 1f64) / (n - 1f64));
+        let mut result = vec![f64::NAN; columns.len()];
+        let mut overnight = RollingStats::default();
+        let mut open_to_close = RollingStats::default();
+        let mut rogers_satchell = 0f64;
+        for (i, term) in terms.iter().enumerate() {
+            overnight = overnight.add(term.0);
+            open_to_close = open_to_close.add(term.1);
+            rogers_satchell += term.2;
+            if i + 1 >= period {
+                let oldest = terms[i + 1 - period];
+                overnight = overnight.remove(oldest.0);
+                open_to_close = open_to_close.remove(oldest.1);
+                rogers_satchell -= oldest.2;
+            }
+            if i + 2 >= period {
+                let variance = overnight.sample_variance()
+                    + k * open_to_close.sample_variance()
+                    + (1f64 - k) * rogers_satchell / n;
+                result[i + 1] = (variance * bars_per_year).sqrt();
+            }
+        }
+        Ok(result)
+    }
+
+    fn rogers_satchell_term<T>(candle: &T) -> f64
+    where
+        T: Candle,
+    {
+        (candle.high_price() / candle.close_price()).ln()
+            * (candle.high_price() / candle.open_price()).ln()
+            + (candle.low_price() / candle.close_price()).ln()
+                * (candle.low_price() / candle.open_price()).ln()
+    }
+
+    /// sorted copy of the data, checks the length and that every price is positive
+    fn prepare<T>(data: &[T], min_len: usize) -> Result<Vec<T>, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        if data.len() < min_len {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        if data.iter().any(|elem| {
+            elem.open_price() <= 0f64
+                || elem.high_price() <= 0f64
+                || elem.low_price() <= 0f64
+                || elem.close_price() <= 0f64
+        }) {
+            return Err(ToolkitError::InvalidData);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+        Ok(data)
+    }
+
+    /// checks the columns like `prepare`, returns the number of bars in a `year`
+    fn prepare_columns(
+        columns: &CandleColumns,
+        period: usize,
+        min_period: usize,
+        year: Time,
+    ) -> Result<f64, ToolkitError> {
+        if period < min_period {
+            return Err(ToolkitError::InvalidData);
+        }
+        columnar::check(columns.len(), period, period)?;
+        if [
+            columns.open(),
+            columns.high(),
+            columns.low(),
+            columns.close(),
+        ]
+        .iter()
+        .any(|prices| prices.iter().any(|price| *price <= 0f64))
+        {
+            return Err(ToolkitError::InvalidData);
+        }
+        let interval = Time::interval_of(columns.epoch_times())?;
+        Ok(year.inner() as f64 / interval.inner() as f64)
+    }
+
+    /// rolling sums of per-bar variance terms / `divisor` → annualised standard deviation
+    fn annualise_sums(terms: &[f64], period: usize, divisor: f64, bars_per_year: f64) -> Vec<f64> {
+        columnar::rolling_sum(terms, period)
+            .into_iter()
+            .map(|sum| {
+                // NaN은 warmup 구간이므로 유지
+                let variance = sum / divisor;
+                if variance < 0f64 {
+                    0f64
+                } else {
+                    (variance * bars_per_year).sqrt()
+                }
+            })
+            .collect()
+    }
+
+    /// per-bar variance → annualised standard deviation
+    /// number of bars in a `year` is inferred from the bar interval of the data
+    fn annualise<T>(data: &[T], variance: f64, year: Time) -> Result<f64, ToolkitError>
+    where
+        T: Candle,
+    {
+        let epoch_times: Vec<u128> = data.iter().map(|elem| elem.epoch_time()).collect();
+        let interval = Time::interval_of(&epoch_times)?;
+        let bars_per_year = year.inner() as f64 / interval.inner() as f64;
+        Ok((variance * bars_per_year).sqrt())
+    }
+}
@@ -0,0 +1,87 @@
+use super::columnar;
+use crate::types::{
+    data::{BaseData, Candle, CandleColumns},
+    error::ToolkitError,
+};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WilliamsR {
+    inner: f64,
+    epoch_time: u128,
+}
+
+impl BaseData for WilliamsR {
+    fn value(&self) -> f64 {
+        self.inner
+    }
+
+    fn weight(&self) -> u64 {
+        1
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.epoch_time
+    }
+}
+
+impl WilliamsR {
+    /// Williams %R over the latest `period` bars
+    /// ranges from -100 to 0, NaN if the highest high equals the lowest low
+    pub fn new<T>(data: &[T], period: usize) -> Result<Self, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        if period == 0 {
+            return Err(ToolkitError::InvalidData);
+        }
+        if data.len() < period {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+        let window = &data[data.len() - period..];
+        let last = window.last().unwrap(); // it's safe since period > 0
+
+        let mut max_high_price = f64::MIN;
+        let mut min_low_price = f64::MAX;
+        for elem in window.iter() {
+            max_high_price = max_high_price.max(elem.high_price());
+            min_low_price = min_low_price.min(elem.low_price());
+        }
+        let inner = if max_high_price == min_low_price {
+            f64::NAN
+        } else {
+            (max_high_price - last.close_price()) / (max_high_price - min_low_price) * -100f64
+        };
+
+        Ok(Self {
+            inner,
+            epoch_time: last.epoch_time(),
+        })
+    }
+
+    /// Williams %R of every bar
+    /// NaN until the first complete period, and where the highest high equals the lowest low
+    pub fn columnar(columns: &CandleColumns, period: usize) -> Result<Vec<f64>, ToolkitError> {
+        columnar::check(columns.len(), period, period)?;
+        let highs = columnar::rolling_max(columns.high(), period);
+        let lows = columnar::rolling_min(columns.low(), period);
+        Ok(columns
+            .close()
+            .iter()
+            .zip(highs.iter().zip(lows.iter()))
+            .map(|(close, (high, low))| {
+                if high == low {
+                    f64::NAN
+                } else {
+                    (high - close) / (high - low) * -100f64
+                }
+            })
+            .collect())
+    }
+
+    pub fn inner(&self) -> f64 {
+        self.inner
+    }
+}
@@ -0,0 +1,151 @@
+use super::{AverageTrueRange, SwingKind, SwingPoint};
+use crate::types::{
+    data::{Candle, CandleColumns, Point},
+    error::ToolkitError,
+};
+
+/// ZigZag
+/// alternating swing highs/lows, a swing point is confirmed when the price
+/// reverses from it by more than the threshold
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ZigZag {
+    /// reversal of a ratio of the swing price, e.g. 0.05 for 5%
+    Percent(f64),
+    /// reversal of ATR(period) * multiplier
+    Atr(usize, f64),
+}
+
+impl ZigZag {
+    /// confirmed swing points of the data
+    /// InvalidData unless the ratio or the multiplier is finite and positive
+    pub fn pivots<T>(&self, data: &[T]) -> Result<Vec<SwingPoint>, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        if data.is_empty() {
+            return Err(ToolkitError::EmptyData);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+
+        // ATR threshold of each bar, None until ATR is available
+        let thresholds: Vec<Option<f64>> = match *self {
+            Self::Percent(ratio) => {
+                if !ratio.is_finite() || ratio <= 0f64 {
+                    return Err(ToolkitError::InvalidData);
+                }
+                vec![]
+            }
+            Self::Atr(period, multiplier) => {
+                if !multiplier.is_finite() || multiplier <= 0f64 {
+                    return Err(ToolkitError::InvalidData);
+                }
+                let atr = AverageTrueRange::series(&data, period)?;
+                let mut thresholds = vec![None; period];
+                thresholds.extend(atr.iter().map(|elem| Some(elem.inner() * multiplier)));
+                thresholds
+            }
+        };
+        Ok(self.sorted_pivots(&data, &thresholds))
+    }
+
+    /// `pivots` of sorted candle columns
+    pub fn pivots_columnar(
+        &self,
+        columns: &CandleColumns,
+    ) -> Result<Vec<SwingPoint>, ToolkitError> {
+        if columns.is_empty() {
+            return Err(ToolkitError::EmptyData);
+        }
+        let thresholds: Vec<Option<f64>> = match *self {
+            Self::Percent(ratio) => {
+                if !ratio.is_finite() || ratio <= 0f64 {
+                    return Err(ToolkitError::InvalidData);
+                }
+                vec![]
+            }
+            Self::Atr(period, multiplier) => {
+                if !multiplier.is_finite() || multiplier <= 0f64 {
+                    return Err(ToolkitError::InvalidData);
+                }
+                AverageTrueRange::columnar(columns, period)?
+                    .into_iter()
+                    .map(|atr| (!atr.is_nan()).then_some(atr * multiplier))
+                    .collect()
+            }
+        };
+        Ok(self.sorted_pivots(&columns.candles(), &thresholds))
+    }
+
+    fn sorted_pivots<T>(&self, data: &[T], thresholds: &[Option<f64>]) -> Vec<SwingPoint>
+    where
+        T: Candle,
+    {
+        let reversal = |i: usize, extreme: f64| -> Option<f64> {
+            match *self {
+                Self::Percent(ratio) => Some(extreme.abs() * ratio),
+                Self::Atr(..) => thresholds[i],
+            }
+        };
+
+        let mut pivots = vec![];
+        // 방향이 정해지기 전에는 최고가/최저가 후보를 모두 추적
+        let mut direction: Option<SwingKind> = None;
+        let mut high = (0usize, data[0].high_price());
+        let mut low = (0usize, data[0].low_price());
+        for (i, elem) in data.iter().enumerate() {
+            if elem.high_price() > high.1 {
+                high = (i, elem.high_price());
+            }
+            if elem.low_price() < low.1 {
+                low = (i, elem.low_price());
+            }
+            let confirm_high = direction != Some(SwingKind::High)
+                && reversal(i, high.1).is_some_and(|r| high.1 - elem.low_price() > r);
+            let confirm_low = direction != Some(SwingKind::Low)
+                && reversal(i, low.1).is_some_and(|r| elem.high_price() - low.1 > r);
+            let swing = match (confirm_high, confirm_low) {
+                (true, true) if high.0 < low.0 => Some((SwingKind::High, high)),
+                (true, true) => Some((SwingKind::Low, low)),
+                (true, false) => Some((SwingKind::High, high)),
+                (false, true) => Some((SwingKind::Low, low)),
+                (false, false) => None,
+            };
+            if let Some((kind, (index, price))) = swing {
+                pivots.push(SwingPoint {
+                    kind,
+                    point: Point::new(price, data[index].epoch_time()),
+                    confirmed_at: elem.epoch_time(),
+                    lag: i - index,
+                });
+                direction = Some(kind);
+                // 확정된 swing point 이후 구간에서 반대 극값을 새로 추적
+                let since = data[index..=i].iter().enumerate();
+                match kind {
+                    SwingKind::High => {
+                        low = since.fold((index, f64::MAX), |acc, (j, elem)| {
+                            if elem.low_price() < acc.1 {
+                                (index + j, elem.low_price())
+                            } else {
+                                acc
+                            }
+                        });
+                        high = (i, elem.high_price());
+                    }
+                    SwingKind::Low => {
+                        high = since.fold((index, f64::MIN), |acc, (j, elem)| {
+                            if elem.high_price() > acc.1 {
+                                (index + j, elem.high_price())
+                            } else {
+                                acc
+                            }
+                        });
+                        low = (i, elem.low_price());
+                    }
+                }
+            }
+        }
+        pivots
+    }
+}
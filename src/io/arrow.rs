@@ -0,0 +1,345 @@
+use crate::types::{
+    data::{BaseData, Candle, CandleColumns},
+    error::ToolkitError,
+};
+use arrow_array::{
+    Array, ArrayRef, Float64Array, RecordBatch, TimestampMillisecondArray,
+    cast::AsArray,
+    types::{
+        Float64Type, Int64Type, TimestampMicrosecondType, TimestampMillisecondType,
+        TimestampNanosecondType, TimestampSecondType, UInt64Type,
+    },
+};
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// column names of an OHLCV record batch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArrowColumns {
+    pub timestamp: String,
+    pub open: String,
+    pub high: String,
+    pub low: String,
+    pub close: String,
+    pub volume: String,
+}
+
+/// OHLCV columns read from Arrow record batches
+/// prices are kept as Arrow arrays, so a single batch is read without copying them
+/// indicators are meant to read them through `columns()` and their `columnar` functions
+#[derive(Debug, Clone)]
+pub struct ArrowCandles {
+    open: Float64Array,
+    high: Float64Array,
+    low: Float64Array,
+    close: Float64Array,
+    volume: Vec<u64>,
+    /// milliseconds since UNIX epoch
+    epoch_times: Vec<u128>,
+}
+
+/// a row of `ArrowCandles`, usable wherever a `Candle` is expected
+#[derive(Debug, Clone, Copy)]
+pub struct ArrowCandle<'a> {
+    candles: &'a ArrowCandles,
+    index: usize,
+}
+
+impl Default for ArrowColumns {
+    fn default() -> Self {
+        Self {
+            timestamp: "timestamp".to_string(),
+            open: "open".to_string(),
+            high: "high".to_string(),
+            low: "low".to_string(),
+            close: "close".to_string(),
+            volume: "volume".to_string(),
+        }
+    }
+}
+
+impl ArrowCandles {
+    /// prices must be non-null Float64 columns
+    /// volume can be UInt64, Int64 or Float64(rounded)
+    /// timestamp can be Int64/UInt64 milliseconds or an Arrow Timestamp of any unit
+    pub fn new(batch: &RecordBatch, columns: &ArrowColumns) -> Result<Self, ToolkitError> {
+        Ok(Self {
+            open: Self::read_prices(batch, &columns.open)?,
+            high: Self::read_prices(batch, &columns.high)?,
+            low: Self::read_prices(batch, &columns.low)?,
+            close: Self::read_prices(batch, &columns.close)?,
+            volume: Self::read_volume(batch, &columns.volume)?,
+            epoch_times: Self::read_epoch_times(batch, &columns.timestamp)?,
+        })
+    }
+
+    /// concatenate the batches, e.g. row groups of a parquet file
+    pub fn from_batches(
+        batches: &[RecordBatch],
+        columns: &ArrowColumns,
+    ) -> Result<Self, ToolkitError> {
+        if batches.len() == 1 {
+            return Self::new(&batches[0], columns);
+        }
+        let mut open = vec![];
+        let mut high = vec![];
+        let mut low = vec![];
+        let mut close = vec![];
+        let mut volume = vec![];
+        let mut epoch_times = vec![];
+        for batch in batches.iter() {
+            let candles = Self::new(batch, columns)?;
+            open.extend_from_slice(candles.open());
+            high.extend_from_slice(candles.high());
+            low.extend_from_slice(candles.low());
+            close.extend_from_slice(candles.close());
+            volume.extend(candles.volume);
+            epoch_times.extend(candles.epoch_times);
+        }
+        Ok(Self {
+            open: Float64Array::from(open),
+            high: Float64Array::from(high),
+            low: Float64Array::from(low),
+            close: Float64Array::from(close),
+            volume,
+            epoch_times,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.epoch_times.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.epoch_times.is_empty()
+    }
+
+    pub fn open(&self) -> &[f64] {
+        self.open.values()
+    }
+
+    pub fn high(&self) -> &[f64] {
+        self.high.values()
+    }
+
+    pub fn low(&self) -> &[f64] {
+        self.low.values()
+    }
+
+    pub fn close(&self) -> &[f64] {
+        self.close.values()
+    }
+
+    pub fn volume(&self) -> &[u64] {
+        &self.volume
+    }
+
+    /// milliseconds since UNIX epoch
+    pub fn epoch_times(&self) -> &[u128] {
+        &self.epoch_times
+    }
+
+    /// columns for the `columnar` functions of indicators
+    /// InvalidData if the rows are not sorted by epoch time
+    pub fn columns(&self) -> Result<CandleColumns<'_>, ToolkitError> {
+        CandleColumns::new(
+            self.open(),
+            self.high(),
+            self.low(),
+            self.close(),
+            &self.volume,
+            &self.epoch_times,
+        )
+    }
+
+    /// rows in the order of the batch, to be passed to the generic indicator functions
+    /// a slow fallback: the generic functions clone and sort the rows,
+    /// `columns()` with the `columnar` functions reads the arrays in place
+    pub fn candles(&self) -> Vec<ArrowCandle<'_>> {
+        (0..self.len())
+            .map(|index| ArrowCandle {
+                candles: self,
+                index,
+            })
+            .collect()
+    }
+
+    /// record batch of a millisecond timestamp column per candle, followed by a Float64 column per indicator series
+    /// indicator values are matched by epoch time, and null if missing
+    pub fn indicator_batch<P>(&self, series: &[(&str, &[P])]) -> Result<RecordBatch, ToolkitError>
+    where
+        P: BaseData,
+    {
+        let timestamps = self
+            .epoch_times
+            .iter()
+            .map(|epoch_time| i64::try_from(*epoch_time).map_err(|_| ToolkitError::InvalidData))
+            .collect::<Result<Vec<i64>, ToolkitError>>()?;
+        let mut fields = vec![Field::new(
+            "timestamp",
+            DataType::Timestamp(TimeUnit::Millisecond, None),
+            false,
+        )];
+        let mut arrays: Vec<ArrayRef> = vec![Arc::new(TimestampMillisecondArray::from(timestamps))];
+        for (name, values) in series.iter() {
+            let values: HashMap<u128, f64> = values
+                .iter()
+                .map(|value| (value.epoch_time(), value.value()))
+                .collect();
+            fields.push(Field::new(*name, DataType::Float64, true));
+            arrays.push(Arc::new(Float64Array::from_iter(
+                self.epoch_times
+                    .iter()
+                    .map(|epoch_time| values.get(epoch_time).copied()),
+            )));
+        }
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays)
+            .map_err(|e| ToolkitError::Schema(e.to_string()))
+    }
+
+    fn column<'a>(batch: &'a RecordBatch, name: &str) -> Result<&'a ArrayRef, ToolkitError> {
+        let column = batch
+            .column_by_name(name)
+            .ok_or_else(|| ToolkitError::Schema(format!("column {name:?} not found")))?;
+        if column.null_count() > 0 {
+            return Err(ToolkitError::Schema(format!("column {name:?} has nulls")));
+        }
+        Ok(column)
+    }
+
+    fn read_prices(batch: &RecordBatch, name: &str) -> Result<Float64Array, ToolkitError> {
+        Self::column(batch, name)?
+            .as_primitive_opt::<Float64Type>()
+            .cloned()
+            .ok_or_else(|| ToolkitError::Schema(format!("column {name:?} is not Float64")))
+    }
+
+    fn read_volume(batch: &RecordBatch, name: &str) -> Result<Vec<u64>, ToolkitError> {
+        let column = Self::column(batch, name)?;
+        let invalid = || ToolkitError::Schema(format!("column {name:?} has a negative volume"));
+        match column.data_type() {
+            DataType::UInt64 => Ok(column.as_primitive::<UInt64Type>().values().to_vec()),
+            DataType::Int64 => column
+                .as_primitive::<Int64Type>()
+                .values()
+                .iter()
+                .map(|volume| u64::try_from(*volume).map_err(|_| invalid()))
+                .collect(),
+            DataType::Float64 => column
+                .as_primitive::<Float64Type>()
+                .values()
+                .iter()
+                .map(|volume| {
+                    if volume.is_finite() && *volume >= 0f64 {
+                        Ok(volume.round() as u64)
+                    } else {
+                        Err(invalid())
+                    }
+                })
+                .collect(),
+            data_type => Err(ToolkitError::Schema(format!(
+                "column {name:?} has an unsupported type {data_type}"
+            ))),
+        }
+    }
+
+    fn read_epoch_times(batch: &RecordBatch, name: &str) -> Result<Vec<u128>, ToolkitError> {
+        let column = Self::column(batch, name)?;
+        // (값, millisecond 배율) 변환
+        let (values, to_millis): (Vec<i64>, fn(i64) -> i64) = match column.data_type() {
+            DataType::Int64 => (column.as_primitive::<Int64Type>().values().to_vec(), |v| v),
+            DataType::UInt64 => {
+                return Ok(column
+                    .as_primitive::<UInt64Type>()
+                    .values()
+                    .iter()
+                    .map(|v| *v as u128)
+                    .collect());
+            }
+            DataType::Timestamp(TimeUnit::Second, _) => (
+                column
+                    .as_primitive::<TimestampSecondType>()
+                    .values()
+                    .to_vec(),
+                |v| v * 1000,
+            ),
+            DataType::Timestamp(TimeUnit::Millisecond, _) => (
+                column
+                    .as_primitive::<TimestampMillisecondType>()
+                    .values()
+                    .to_vec(),
+                |v| v,
+            ),
+            DataType::Timestamp(TimeUnit::Microsecond, _) => (
+                column
+                    .as_primitive::<TimestampMicrosecondType>()
+                    .values()
+                    .to_vec(),
+                |v| v / 1000,
+            ),
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => (
+                column
+                    .as_primitive::<TimestampNanosecondType>()
+                    .values()
+                    .to_vec(),
+                |v| v / 1_000_000,
+            ),
+            data_type => {
+                return Err(ToolkitError::Schema(format!(
+                    "column {name:?} has an unsupported type {data_type}"
+                )));
+            }
+        };
+        values
+            .into_iter()
+            .map(|v| {
+                u128::try_from(to_millis(v)).map_err(|_| {
+                    ToolkitError::Schema(format!(
+                        "column {name:?} has a timestamp before UNIX epoch"
+                    ))
+                })
+            })
+            .collect()
+    }
+}
+
+impl Candle for ArrowCandle<'_> {
+    fn open_price(&self) -> f64 {
+        self.candles.open()[self.index]
+    }
+
+    fn high_price(&self) -> f64 {
+        self.candles.high()[self.index]
+    }
+
+    fn low_price(&self) -> f64 {
+        self.candles.low()[self.index]
+    }
+
+    fn close_price(&self) -> f64 {
+        self.candles.close()[self.index]
+    }
+
+    fn tot_exec_volume(&self) -> u64 {
+        self.candles.volume[self.index]
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.candles.epoch_times[self.index]
+    }
+}
+
+impl BaseData for ArrowCandle<'_> {
+    fn value(&self) -> f64 {
+        self.close_price()
+    }
+
+    fn weight(&self) -> u64 {
+        self.tot_exec_volume()
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.candles.epoch_times[self.index]
+    }
+}
@@ -0,0 +1,283 @@
+use crate::types::{
+    data::{Candle, Ohlcv},
+    error::ToolkitError,
+};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat};
+use std::io::{Read, Write};
+
+/// column of a csv file, by header name or 0-based index
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Column {
+    Name(String),
+    Index(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Columns {
+    pub epoch_time: Column,
+    pub open: Column,
+    pub high: Column,
+    pub low: Column,
+    pub close: Column,
+    pub volume: Column,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimestampFormat {
+    /// milliseconds since UNIX epoch
+    EpochMillis,
+    /// seconds since UNIX epoch, fractions allowed
+    EpochSeconds,
+    /// e.g. `2024-01-02T09:30:00Z`, `2024-01-02T09:30:00+09:00`, `2024-01-02 09:30:00`, `2024-01-02`
+    /// timestamps without an offset are UTC
+    Iso8601,
+}
+
+/// csv format of candles
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Csv {
+    pub delimiter: u8,
+    pub has_headers: bool,
+    pub columns: Columns,
+    pub timestamp: TimestampFormat,
+}
+
+impl Default for Columns {
+    fn default() -> Self {
+        Self {
+            epoch_time: Column::Name("time".to_string()),
+            open: Column::Name("open".to_string()),
+            high: Column::Name("high".to_string()),
+            low: Column::Name("low".to_string()),
+            close: Column::Name("close".to_string()),
+            volume: Column::Name("volume".to_string()),
+        }
+    }
+}
+
+impl Default for Csv {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            has_headers: true,
+            columns: Columns::default(),
+            timestamp: TimestampFormat::EpochMillis,
+        }
+    }
+}
+
+impl TimestampFormat {
+    pub fn parse(&self, field: &str) -> Result<u128, String> {
+        let field = field.trim();
+        match self {
+            Self::EpochMillis => field
+                .parse::<u128>()
+                .map_err(|e| format!("invalid epoch milliseconds {field:?}: {e}")),
+            Self::EpochSeconds => match field.parse::<u128>() {
+                Ok(seconds) => Ok(seconds * 1000),
+                Err(_) => match field.parse::<f64>() {
+                    Ok(seconds) if seconds.is_finite() && seconds >= 0f64 => {
+                        Ok((seconds * 1000f64).round() as u128)
+                    }
+                    _ => Err(format!("invalid epoch seconds {field:?}")),
+                },
+            },
+            Self::Iso8601 => {
+                let millis = if let Ok(datetime) = DateTime::parse_from_rfc3339(field) {
+                    datetime.timestamp_millis()
+                } else if let Ok(datetime) =
+                    NaiveDateTime::parse_from_str(field, "%Y-%m-%dT%H:%M:%S%.f")
+                        .or_else(|_| NaiveDateTime::parse_from_str(field, "%Y-%m-%d %H:%M:%S%.f"))
+                {
+                    datetime.and_utc().timestamp_millis()
+                } else if let Ok(date) = NaiveDate::parse_from_str(field, "%Y-%m-%d") {
+                    // it's safe since midnight always exists
+                    date.and_hms_opt(0, 0, 0)
+                        .unwrap()
+                        .and_utc()
+                        .timestamp_millis()
+                } else {
+                    return Err(format!("invalid ISO-8601 timestamp {field:?}"));
+                };
+                u128::try_from(millis).map_err(|_| format!("timestamp before UNIX epoch {field:?}"))
+            }
+        }
+    }
+
+    pub fn format(&self, epoch_time: u128) -> String {
+        match self {
+            Self::EpochMillis => epoch_time.to_string(),
+            Self::EpochSeconds if epoch_time.is_multiple_of(1000) => {
+                (epoch_time / 1000).to_string()
+            }
+            Self::EpochSeconds => format!("{}.{:03}", epoch_time / 1000, epoch_time % 1000),
+            Self::Iso8601 => i64::try_from(epoch_time)
+                .ok()
+                .and_then(DateTime::from_timestamp_millis)
+                .map(|datetime| datetime.to_rfc3339_opts(SecondsFormat::Millis, true))
+                .unwrap_or_else(|| epoch_time.to_string()),
+        }
+    }
+}
+
+impl Csv {
+    /// read candles, errors carry the line number of the file
+    pub fn read<R>(&self, reader: R) -> Result<Vec<Ohlcv>, ToolkitError>
+    where
+        R: Read,
+    {
+        let mut reader = ::csv::ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .has_headers(self.has_headers)
+            .from_reader(reader);
+        let headers = if self.has_headers {
+            Some(reader.headers().map_err(Self::error)?.clone())
+        } else {
+            None
+        };
+        let index = |column: &Column| match column {
+            Column::Index(index) => Ok(*index),
+            Column::Name(name) => headers
+                .as_ref()
+                .and_then(|headers| headers.iter().position(|header| header.trim() == name))
+                .ok_or_else(|| ToolkitError::Parse {
+                    line: 1,
+                    message: format!("column {name:?} not found"),
+                }),
+        };
+        let columns = [
+            index(&self.columns.epoch_time)?,
+            index(&self.columns.open)?,
+            index(&self.columns.high)?,
+            index(&self.columns.low)?,
+            index(&self.columns.close)?,
+            index(&self.columns.volume)?,
+        ];
+
+        let mut data = vec![];
+        for record in reader.records() {
+            let record = record.map_err(Self::error)?;
+            let line = record
+                .position()
+                .map(|position| position.line())
+                .unwrap_or(0);
+            let field = |i: usize| {
+                record.get(columns[i]).ok_or_else(|| ToolkitError::Parse {
+                    line,
+                    message: format!("column {} not found", columns[i]),
+                })
+            };
+            let price = |i: usize| {
+                let field = field(i)?.trim();
+                field.parse::<f64>().map_err(|e| ToolkitError::Parse {
+                    line,
+                    message: format!("invalid price {field:?}: {e}"),
+                })
+            };
+            let epoch_time = self
+                .timestamp
+                .parse(field(0)?)
+                .map_err(|message| ToolkitError::Parse { line, message })?;
+            let volume = field(5)?.trim();
+            let volume = match volume.parse::<u64>() {
+                Ok(volume) => volume,
+                Err(_) => match volume.parse::<f64>() {
+                    Ok(f) if f.is_finite() && f >= 0f64 => f.round() as u64,
+                    _ => {
+                        return Err(ToolkitError::Parse {
+                            line,
+                            message: format!("invalid volume {volume:?}"),
+                        });
+                    }
+                },
+            };
+            data.push(Ohlcv::new(
+                price(1)?,
+                price(2)?,
+                price(3)?,
+                price(4)?,
+                volume,
+                epoch_time,
+            ));
+        }
+        Ok(data)
+    }
+
+    /// write candles sorted by epoch time, followed by a column per indicator series
+    /// a series has a value per candle in epoch time order, e.g. the output of a `columnar` function,
+    /// and NaN values are left empty
+    /// columns are written in the default order, named after `Column::Name` if given
+    /// InvalidData if a series differs in length from the candles
+    pub fn write<W, T>(
+        &self,
+        writer: W,
+        data: &[T],
+        series: &[(&str, &[f64])],
+    ) -> Result<(), ToolkitError>
+    where
+        W: Write,
+        T: Candle + Clone,
+    {
+        if series.iter().any(|(_, values)| values.len() != data.len()) {
+            return Err(ToolkitError::InvalidData);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+        let names: Vec<String> = series.iter().map(|(name, _)| name.to_string()).collect();
+
+        let mut writer = ::csv::WriterBuilder::new()
+            .delimiter(self.delimiter)
+            .from_writer(writer);
+        if self.has_headers {
+            let name = |column: &Column, default: &str| match column {
+                Column::Name(name) => name.clone(),
+                Column::Index(_) => default.to_string(),
+            };
+            let mut headers = vec![
+                name(&self.columns.epoch_time, "time"),
+                name(&self.columns.open, "open"),
+                name(&self.columns.high, "high"),
+                name(&self.columns.low, "low"),
+                name(&self.columns.close, "close"),
+                name(&self.columns.volume, "volume"),
+            ];
+            headers.extend(names);
+            writer.write_record(&headers).map_err(Self::error)?;
+        }
+        for (i, elem) in data.iter().enumerate() {
+            let mut record = vec![
+                self.timestamp.format(elem.epoch_time()),
+                elem.open_price().to_string(),
+                elem.high_price().to_string(),
+                elem.low_price().to_string(),
+                elem.close_price().to_string(),
+                elem.tot_exec_volume().to_string(),
+            ];
+            record.extend(series.iter().map(|(_, values)| {
+                if values[i].is_nan() {
+                    String::new()
+                } else {
+                    values[i].to_string()
+                }
+            }));
+            writer.write_record(&record).map_err(Self::error)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    fn error(e: ::csv::Error) -> ToolkitError {
+        let line = e.position().map(|position| position.line()).unwrap_or(0);
+        match e.into_kind() {
+            ::csv::ErrorKind::Io(e) => ToolkitError::Io(e),
+            kind => ToolkitError::Parse {
+                line,
+                message: format!("{kind:?}"),
+            },
+        }
+    }
+}
@@ -0,0 +1,14 @@
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(feature = "csv")]
+mod csv;
+#[cfg(feature = "parquet")]
+mod parquet;
+mod test;
+
+#[cfg(feature = "arrow")]
+pub use arrow::{ArrowCandle, ArrowCandles, ArrowColumns};
+#[cfg(feature = "csv")]
+pub use csv::{Column, Columns, Csv, TimestampFormat};
+#[cfg(feature = "parquet")]
+pub use parquet::{read_parquet, write_parquet};
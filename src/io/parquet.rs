@@ -0,0 +1,37 @@
+use crate::types::error::ToolkitError;
+use arrow_array::RecordBatch;
+use parquet::arrow::{ArrowWriter, arrow_reader::ParquetRecordBatchReaderBuilder};
+use parquet::errors::ParquetError;
+use parquet::file::reader::ChunkReader;
+use std::io::Write;
+
+/// read every record batch of a parquet file, e.g. to build `ArrowCandles::from_batches`
+pub fn read_parquet<R>(reader: R) -> Result<Vec<RecordBatch>, ToolkitError>
+where
+    R: ChunkReader + 'static,
+{
+    ParquetRecordBatchReaderBuilder::try_new(reader)
+        .and_then(|builder| builder.build())
+        .map_err(error)?
+        .map(|batch| batch.map_err(|e| ToolkitError::Schema(e.to_string())))
+        .collect()
+}
+
+/// write the record batch as a parquet file, e.g. from `ArrowCandles::indicator_batch`
+pub fn write_parquet<W>(writer: W, batch: &RecordBatch) -> Result<(), ToolkitError>
+where
+    W: Write + Send,
+{
+    let mut writer = ArrowWriter::try_new(writer, batch.schema(), None).map_err(error)?;
+    writer.write(batch).map_err(error)?;
+    writer.close().map_err(error)?;
+    Ok(())
+}
+
+fn error(e: ParquetError) -> ToolkitError {
+    match e {
+        ParquetError::External(e) => ToolkitError::Io(std::io::Error::other(e)),
+        ParquetError::ArrowError(message) => ToolkitError::Schema(message),
+        e => ToolkitError::Io(std::io::Error::other(e)),
+    }
+}
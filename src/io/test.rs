@@ -0,0 +1,283 @@
+#[cfg(all(test, feature = "csv"))]
+mod tests {
+    use crate::indicator::{Momentum, MovingAverage};
+    use crate::io::{Column, Columns, Csv, TimestampFormat};
+    use crate::types::{data::Ohlcv, error::ToolkitError};
+
+    #[test]
+    fn test_read_csv() {
+        let text = "time,open,high,low,close,volume\n\
+                    1704153600000,100.0,101.5,99.0,101.0,1000\n\
+                    1704240000000,101.0,102.0,100.5,101.5,1200.0\n";
+        let data = Csv::default().read(text.as_bytes()).unwrap();
+        assert_eq!(
+            vec![
+                Ohlcv::new(100.0, 101.5, 99.0, 101.0, 1000, 1704153600000),
+                Ohlcv::new(101.0, 102.0, 100.5, 101.5, 1200, 1704240000000),
+            ],
+            data
+        );
+
+        // header 없이 index로 column 지정, 세미콜론 구분, ISO-8601
+        let text = "2024-01-02T00:00:00Z;1000;100.0;101.5;99.0;101.0\n\
+                    2024-01-03 09:00:00+09:00;1200;101.0;102.0;100.5;101.5\n";
+        let csv = Csv {
+            delimiter: b';',
+            has_headers: false,
+            columns: Columns {
+                epoch_time: Column::Index(0),
+                open: Column::Index(2),
+                high: Column::Index(3),
+                low: Column::Index(4),
+                close: Column::Index(5),
+                volume: Column::Index(1),
+            },
+            timestamp: TimestampFormat::Iso8601,
+        };
+        let data = csv.read(text.as_bytes()).unwrap();
+        assert_eq!(1704153600000, data[0].epoch_time);
+        assert_eq!(1704240000000, data[1].epoch_time);
+        assert_eq!(1200, data[1].volume);
+
+        assert_eq!(
+            Ok(1704153600500),
+            TimestampFormat::EpochSeconds.parse("1704153600.5")
+        );
+        assert_eq!(
+            Ok(1704153600000),
+            TimestampFormat::Iso8601.parse("2024-01-02")
+        );
+        assert!(
+            TimestampFormat::Iso8601
+                .parse("1969-12-31T00:00:00Z")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_read_csv_error() {
+        let text = "time,open,high,low,close,volume\n\
+                    1704153600000,100.0,101.5,99.0,101.0,1000\n\
+                    1704240000000,101.0,abc,100.5,101.5,1200\n";
+        match Csv::default().read(text.as_bytes()) {
+            Err(ToolkitError::Parse { line, message }) => {
+                assert_eq!(3, line);
+                assert!(message.contains("abc"));
+            }
+            _ => panic!("expected a parse error"),
+        }
+
+        let text = "date,open,high,low,close,volume\n";
+        match Csv::default().read(text.as_bytes()) {
+            Err(ToolkitError::Parse { line, .. }) => assert_eq!(1, line),
+            _ => panic!("expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let data = vec![
+            Ohlcv::new(101.0, 102.0, 100.5, 101.5, 1200, 1704240000000),
+            Ohlcv::new(100.0, 101.5, 99.0, 101.0, 1000, 1704153600000),
+            Ohlcv::new(101.5, 103.0, 101.0, 102.5, 900, 1704326400000),
+        ];
+        let mut sorted = data.clone();
+        sorted.sort_by_key(|k| k.epoch_time);
+        let close: Vec<f64> = sorted.iter().map(|elem| elem.close).collect();
+        let sma = MovingAverage::simple_columnar(&close, 2).unwrap();
+        let momentum = Momentum::columnar(&close, 1).unwrap();
+
+        let csv = Csv {
+            timestamp: TimestampFormat::Iso8601,
+            ..Default::default()
+        };
+        let mut buffer = vec![];
+        csv.write(
+            &mut buffer,
+            &data,
+            &[("sma_2", &sma), ("momentum_1", &momentum)],
+        )
+        .unwrap();
+        let text = String::from_utf8(buffer).unwrap();
+        assert_eq!(
+            "time,open,high,low,close,volume,sma_2,momentum_1\n\
+             2024-01-02T00:00:00.000Z,100,101.5,99,101,1000,,\n\
+             2024-01-03T00:00:00.000Z,101,102,100.5,101.5,1200,101.25,0.5\n\
+             2024-01-04T00:00:00.000Z,101.5,103,101,102.5,900,102,1\n",
+            text
+        );
+        assert!(csv.write(vec![], &data, &[("sma_2", &sma[1..])]).is_err());
+
+        // 다시 읽으면 같은 candle
+        assert_eq!(sorted, csv.read(text.as_bytes()).unwrap());
+    }
+}
+
+#[cfg(all(test, feature = "arrow"))]
+mod arrow_tests {
+    use crate::indicator::AverageTrueRange;
+    use crate::io::{ArrowCandles, ArrowColumns};
+    use crate::types::{
+        data::{Candle, Ohlcv},
+        error::ToolkitError,
+    };
+    use arrow_array::{
+        Array, ArrayRef, Float64Array, Int64Array, RecordBatch, TimestampSecondArray,
+        cast::AsArray,
+        types::{Float64Type, TimestampMillisecondType},
+    };
+    use std::sync::Arc;
+
+    const MAX_ERR: f64 = 1e-10;
+
+    fn data() -> Vec<Ohlcv> {
+        (0..20)
+            .map(|i| {
+                let base = 100.0 + (i as f64 * 0.7).sin() * 5.0;
+                Ohlcv::new(
+                    base,
+                    base + 1.5,
+                    base - 1.0,
+                    base + 0.5,
+                    1000 + i * 10,
+                    1704153600000 + i as u128 * 86400000,
+                )
+            })
+            .collect()
+    }
+
+    fn batch(data: &[Ohlcv]) -> RecordBatch {
+        let prices = |f: fn(&Ohlcv) -> f64| -> ArrayRef {
+            Arc::new(Float64Array::from_iter_values(data.iter().map(f)))
+        };
+        RecordBatch::try_from_iter(vec![
+            (
+                "ts",
+                Arc::new(TimestampSecondArray::from_iter_values(
+                    data.iter().map(|elem| (elem.epoch_time / 1000) as i64),
+                )) as ArrayRef,
+            ),
+            ("open", prices(|elem| elem.open)),
+            ("high", prices(|elem| elem.high)),
+            ("low", prices(|elem| elem.low)),
+            ("close", prices(|elem| elem.close)),
+            (
+                "volume",
+                Arc::new(Int64Array::from_iter_values(
+                    data.iter().map(|elem| elem.volume as i64),
+                )) as ArrayRef,
+            ),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_arrow_candles() {
+        let data = data();
+        let columns = ArrowColumns {
+            timestamp: "ts".to_string(),
+            ..Default::default()
+        };
+        let candles = ArrowCandles::new(&batch(&data), &columns).unwrap();
+        assert_eq!(data.len(), candles.len());
+        assert_eq!(data[3].epoch_time, candles.epoch_times()[3]);
+        assert_eq!(data[3].volume, candles.volume()[3]);
+        assert_eq!(data[3].close, candles.close()[3]);
+
+        // 같은 candle이면 같은 indicator
+        let expected = AverageTrueRange::series(&data, 14).unwrap();
+        let actual = AverageTrueRange::series(&candles.candles(), 14).unwrap();
+        assert_eq!(expected.len(), actual.len());
+        for (expected, actual) in expected.iter().zip(actual.iter()) {
+            assert!((expected.inner() - actual.inner()).abs() < MAX_ERR);
+        }
+
+        // 여러 batch
+        let batches = [batch(&data[..8]), batch(&data[8..])];
+        let concatenated = ArrowCandles::from_batches(&batches, &columns).unwrap();
+        assert_eq!(candles.close(), concatenated.close());
+        assert_eq!(candles.epoch_times(), concatenated.epoch_times());
+        assert_eq!(
+            data[10].high_price(),
+            concatenated.candles()[10].high_price()
+        );
+
+        // column이 없거나 type이 다르면 Schema error
+        assert!(matches!(
+            ArrowCandles::new(&batch(&data), &ArrowColumns::default()),
+            Err(ToolkitError::Schema(_))
+        ));
+        let columns = ArrowColumns {
+            timestamp: "ts".to_string(),
+            open: "volume".to_string(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            ArrowCandles::new(&batch(&data), &columns),
+            Err(ToolkitError::Schema(_))
+        ));
+    }
+
+    #[test]
+    fn test_indicator_batch() {
+        let data = data();
+        let columns = ArrowColumns {
+            timestamp: "ts".to_string(),
+            ..Default::default()
+        };
+        let candles = ArrowCandles::new(&batch(&data), &columns).unwrap();
+        let atr = AverageTrueRange::series(&candles.candles(), 14).unwrap();
+        let output = candles.indicator_batch(&[("atr_14", &atr)]).unwrap();
+        assert_eq!(data.len(), output.num_rows());
+        assert_eq!(2, output.num_columns());
+
+        let column = output
+            .column_by_name("atr_14")
+            .unwrap()
+            .as_primitive::<Float64Type>();
+        // 첫 period개 bar는 null
+        assert_eq!(14, column.null_count());
+        assert!(column.is_null(13));
+        assert!((atr[0].inner() - column.value(14)).abs() < MAX_ERR);
+
+        // timestamp는 millisecond
+        let timestamps = output
+            .column(0)
+            .as_primitive::<TimestampMillisecondType>()
+            .values();
+        assert_eq!(data[0].epoch_time as i64, timestamps[0]);
+        assert_eq!(data[19].epoch_time as i64, timestamps[19]);
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_parquet() {
+        use crate::io::{read_parquet, write_parquet};
+
+        let data = data();
+        let columns = ArrowColumns {
+            timestamp: "ts".to_string(),
+            ..Default::default()
+        };
+        let path = std::env::temp_dir().join(format!(
+            "trading_toolkit_test_{}.parquet",
+            std::process::id()
+        ));
+        write_parquet(std::fs::File::create(&path).unwrap(), &batch(&data)).unwrap();
+        let batches = read_parquet(std::fs::File::open(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let candles = ArrowCandles::from_batches(&batches, &columns).unwrap();
+        assert_eq!(data.len(), candles.len());
+        assert_eq!(
+            data.iter().map(|elem| elem.close).collect::<Vec<f64>>(),
+            candles.close()
+        );
+        assert_eq!(
+            data.iter()
+                .map(|elem| elem.epoch_time)
+                .collect::<Vec<u128>>(),
+            candles.epoch_times()
+        );
+    }
+}
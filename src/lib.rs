@@ -1,2 +1,11 @@
+pub mod backtest;
 pub mod indicator;
+pub mod io;
+pub mod pattern;
+pub mod risk;
+pub mod signal;
+pub mod stats;
+#[cfg(test)]
+mod test_util;
+pub mod trading;
 pub mod types;
@@ -0,0 +1,276 @@
+use crate::indicator::Trend;
+use crate::types::{data::Candle, error::ToolkitError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Pattern {
+    Doji,
+    Hammer,
+    ShootingStar,
+    BullishEngulfing,
+    BearishEngulfing,
+    BullishHarami,
+    BearishHarami,
+    MorningStar,
+    EveningStar,
+    ThreeWhiteSoldiers,
+    ThreeBlackCrows,
+    InsideBar,
+    OutsideBar,
+}
+
+/// thresholds of body/shadow sizes
+/// ratios are relative to the bar's range(high - low) unless noted
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PatternConfig {
+    /// max body of a doji
+    pub doji_body: f64,
+    /// min body of a long bar
+    pub long_body: f64,
+    /// max body of a small bar(middle bar of the stars)
+    pub small_body: f64,
+    /// min length of the long shadow of hammer/shooting star, relative to the body
+    pub long_shadow: f64,
+    /// max length of the short shadow of hammer/shooting star
+    pub short_shadow: f64,
+}
+
+impl Default for PatternConfig {
+    fn default() -> Self {
+        Self {
+            doji_body: 0.1f64,
+            long_body: 0.6f64,
+            small_body: 0.3f64,
+            long_shadow: 2f64,
+            short_shadow: 0.1f64,
+        }
+    }
+}
+
+/// patterns completed at a bar
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CandlePatterns {
+    pub epoch_time: u128,
+    pub patterns: Vec<Pattern>,
+}
+
+/// shape of a single bar
+struct Bar {
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+}
+
+impl Bar {
+    fn new<T>(candle: &T) -> Self
+    where
+        T: Candle,
+    {
+        Self {
+            open: candle.open_price(),
+            high: candle.high_price(),
+            low: candle.low_price(),
+            close: candle.close_price(),
+        }
+    }
+
+    fn body(&self) -> f64 {
+        (self.close - self.open).abs()
+    }
+
+    fn range(&self) -> f64 {
+        self.high - self.low
+    }
+
+    fn body_top(&self) -> f64 {
+        self.open.max(self.close)
+    }
+
+    fn body_bottom(&self) -> f64 {
+        self.open.min(self.close)
+    }
+
+    fn upper_shadow(&self) -> f64 {
+        self.high - self.body_top()
+    }
+
+    fn lower_shadow(&self) -> f64 {
+        self.body_bottom() - self.low
+    }
+
+    fn is_bullish(&self) -> bool {
+        self.close > self.open
+    }
+
+    fn is_bearish(&self) -> bool {
+        self.close < self.open
+    }
+
+    fn is_long(&self, config: &PatternConfig) -> bool {
+        self.range() > 0f64 && self.body() >= config.long_body * self.range()
+    }
+
+    fn is_small(&self, config: &PatternConfig) -> bool {
+        self.body() <= config.small_body * self.range()
+    }
+}
+
+impl Pattern {
+    /// expected direction after the pattern, `None` for neutral patterns
+    pub fn direction(&self) -> Option<Trend> {
+        match self {
+            Self::Hammer
+            | Self::BullishEngulfing
+            | Self::BullishHarami
+            | Self::MorningStar
+            | Self::ThreeWhiteSoldiers => Some(Trend::Up),
+            Self::ShootingStar
+            | Self::BearishEngulfing
+            | Self::BearishHarami
+            | Self::EveningStar
+            | Self::ThreeBlackCrows => Some(Trend::Down),
+            Self::Doji | Self::InsideBar | Self::OutsideBar => None,
+        }
+    }
+
+    /// patterns of every bar, a multi-bar pattern belongs to the bar which completes it
+    pub fn detect<T>(
+        data: &[T],
+        config: &PatternConfig,
+    ) -> Result<Vec<CandlePatterns>, ToolkitError>
+    where
+        T: Candle + Clone,
+    {
+        if data.is_empty() {
+            return Err(ToolkitError::EmptyData);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+        let bars: Vec<Bar> = data.iter().map(Bar::new).collect();
+
+        Ok(data
+            .iter()
+            .enumerate()
+            .map(|(i, elem)| {
+                let mut patterns = Self::single(&bars[i], config);
+                if i >= 1 {
+                    patterns.extend(Self::double(&bars[i - 1], &bars[i]));
+                }
+                if i >= 2 {
+                    patterns.extend(Self::triple(&bars[i - 2], &bars[i - 1], &bars[i], config));
+                }
+                CandlePatterns {
+                    epoch_time: elem.epoch_time(),
+                    patterns,
+                }
+            })
+            .collect())
+    }
+
+    fn single(bar: &Bar, config: &PatternConfig) -> Vec<Self> {
+        let mut patterns = vec![];
+        if bar.body() <= config.doji_body * bar.range() {
+            patterns.push(Self::Doji);
+        }
+        if bar.range() > 0f64 {
+            let short_shadow = config.short_shadow * bar.range();
+            if bar.lower_shadow() >= config.long_shadow * bar.body()
+                && bar.lower_shadow() > short_shadow
+                && bar.upper_shadow() <= short_shadow
+            {
+                patterns.push(Self::Hammer);
+            }
+            if bar.upper_shadow() >= config.long_shadow * bar.body()
+                && bar.upper_shadow() > short_shadow
+                && bar.lower_shadow() <= short_shadow
+            {
+                patterns.push(Self::ShootingStar);
+            }
+        }
+        patterns
+    }
+
+    fn double(prev: &Bar, curr: &Bar) -> Vec<Self> {
+        let mut patterns = vec![];
+        let engulfs = curr.body_top() >= prev.body_top()
+            && curr.body_bottom() <= prev.body_bottom()
+            && curr.body() > prev.body();
+        let inside_body = curr.body_top() <= prev.body_top()
+            && curr.body_bottom() >= prev.body_bottom()
+            && curr.body() < prev.body();
+        if prev.is_bearish() && curr.is_bullish() {
+            if engulfs {
+                patterns.push(Self::BullishEngulfing);
+            }
+            if inside_body {
+                patterns.push(Self::BullishHarami);
+            }
+        }
+        if prev.is_bullish() && curr.is_bearish() {
+            if engulfs {
+                patterns.push(Self::BearishEngulfing);
+            }
+            if inside_body {
+                patterns.push(Self::BearishHarami);
+            }
+        }
+        if curr.high < prev.high && curr.low > prev.low {
+            patterns.push(Self::InsideBar);
+        }
+        if curr.high > prev.high && curr.low < prev.low {
+            patterns.push(Self::OutsideBar);
+        }
+        patterns
+    }
+
+    fn triple(first: &Bar, second: &Bar, third: &Bar, config: &PatternConfig) -> Vec<Self> {
+        let mut patterns = vec![];
+        let first_mid = (first.open + first.close) / 2f64;
+        if first.is_bearish()
+            && first.is_long(config)
+            && second.is_small(config)
+            && second.body_top() < first.close
+            && third.is_bullish()
+            && third.close > first_mid
+        {
+            patterns.push(Self::MorningStar);
+        }
+        if first.is_bullish()
+            && first.is_long(config)
+            && second.is_small(config)
+            && second.body_bottom() > first.close
+            && third.is_bearish()
+            && third.close < first_mid
+        {
+            patterns.push(Self::EveningStar);
+        }
+
+        let bars = [first, second, third];
+        if bars
+            .iter()
+            .all(|bar| bar.is_bullish() && bar.is_long(config))
+            && bars.windows(2).all(|pair| {
+                pair[1].close > pair[0].close
+                    && pair[1].open >= pair[0].open
+                    && pair[1].open <= pair[0].close
+            })
+        {
+            patterns.push(Self::ThreeWhiteSoldiers);
+        }
+        if bars
+            .iter()
+            .all(|bar| bar.is_bearish() && bar.is_long(config))
+            && bars.windows(2).all(|pair| {
+                pair[1].close < pair[0].close
+                    && pair[1].open <= pair[0].open
+                    && pair[1].open >= pair[0].close
+            })
+        {
+            patterns.push(Self::ThreeBlackCrows);
+        }
+        patterns
+    }
+}
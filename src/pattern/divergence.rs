@@ -0,0 +1,107 @@
+use crate::indicator::{SwingKind, SwingPoint, Trend};
+use crate::types::{
+    data::{BaseData, Point},
+    error::ToolkitError,
+};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DivergenceKind {
+    /// price makes a lower low, oscillator makes a higher low
+    RegularBullish,
+    /// price makes a higher high, oscillator makes a lower high
+    RegularBearish,
+    /// price makes a higher low, oscillator makes a lower low
+    HiddenBullish,
+    /// price makes a lower high, oscillator makes a higher high
+    HiddenBearish,
+}
+
+/// divergence between 2 consecutive swing points of the price
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Divergence {
+    pub kind: DivergenceKind,
+    /// earlier and later swing points of the price
+    pub price: (Point, Point),
+    /// oscillator values at the price swing points
+    pub oscillator: (Point, Point),
+}
+
+impl Divergence {
+    /// expected direction after the divergence
+    pub fn direction(&self) -> Trend {
+        match self.kind {
+            DivergenceKind::RegularBullish | DivergenceKind::HiddenBullish => Trend::Up,
+            DivergenceKind::RegularBearish | DivergenceKind::HiddenBearish => Trend::Down,
+        }
+    }
+
+    /// detect divergences between the price and an oscillator(MACD histogram, Stochastic, ...)
+    /// swing points of the price are bars higher/lower than `left` bars before and `right` bars after,
+    /// and the oscillator is sampled at the same epoch times
+    pub fn detect<P, O>(
+        price: &[P],
+        oscillator: &[O],
+        left: usize,
+        right: usize,
+    ) -> Result<Vec<Self>, ToolkitError>
+    where
+        P: BaseData + Clone,
+        O: BaseData,
+    {
+        if price.is_empty() || oscillator.is_empty() {
+            return Err(ToolkitError::EmptyData);
+        }
+        let oscillator: HashMap<u128, f64> = oscillator
+            .iter()
+            .map(|elem| (elem.epoch_time(), elem.value()))
+            .collect();
+
+        let swings = SwingPoint::detect_values(price, left, right)?;
+        let mut divergences = vec![];
+        for kind in [SwingKind::High, SwingKind::Low] {
+            // oscillator 값이 있는 swing point만 비교
+            let swings: Vec<(Point, Point)> = swings
+                .iter()
+                .filter(|swing| swing.kind == kind)
+                .filter_map(|swing| {
+                    oscillator
+                        .get(&swing.point.epoch_time)
+                        .map(|value| (swing.point, Point::new(*value, swing.point.epoch_time)))
+                })
+                .collect();
+            for pair in swings.windows(2) {
+                let (prev_price, prev_oscillator) = pair[0];
+                let (curr_price, curr_oscillator) = pair[1];
+                let price_rises = curr_price.value > prev_price.value;
+                let price_falls = curr_price.value < prev_price.value;
+                let oscillator_rises = curr_oscillator.value > prev_oscillator.value;
+                let oscillator_falls = curr_oscillator.value < prev_oscillator.value;
+                let kind = match kind {
+                    SwingKind::High if price_rises && oscillator_falls => {
+                        DivergenceKind::RegularBearish
+                    }
+                    SwingKind::High if price_falls && oscillator_rises => {
+                        DivergenceKind::HiddenBearish
+                    }
+                    SwingKind::Low if price_falls && oscillator_rises => {
+                        DivergenceKind::RegularBullish
+                    }
+                    SwingKind::Low if price_rises && oscillator_falls => {
+                        DivergenceKind::HiddenBullish
+                    }
+                    _ => continue,
+                };
+                divergences.push(Self {
+                    kind,
+                    price: (prev_price, curr_price),
+                    oscillator: (prev_oscillator, curr_oscillator),
+                });
+            }
+        }
+        divergences.sort_by_key(|k| k.price.1.epoch_time);
+        Ok(divergences)
+    }
+}
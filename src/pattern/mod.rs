@@ -0,0 +1,6 @@
+mod candlestick;
+mod divergence;
+mod test;
+
+pub use candlestick::{CandlePatterns, Pattern, PatternConfig};
+pub use divergence::{Divergence, DivergenceKind};
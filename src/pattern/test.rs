@@ -0,0 +1,222 @@
+#[cfg(test)]
+mod tests {
+    use crate::indicator::Trend;
+    use crate::pattern::{Divergence, DivergenceKind, Pattern, PatternConfig};
+    use crate::test_util::day;
+    use crate::types::{
+        data::{Ohlcv, Point},
+        time::Time,
+    };
+
+    fn detect(data: &[Ohlcv]) -> Vec<Vec<Pattern>> {
+        Pattern::detect(data, &PatternConfig::default())
+            .unwrap()
+            .into_iter()
+            .map(|bar| bar.patterns)
+            .collect()
+    }
+
+    #[test]
+    fn test_single_bar_pattern() {
+        let now = Time::now().unwrap();
+        let data = vec![
+            // doji
+            Ohlcv::new(
+                100.0,
+                105.0,
+                95.0,
+                100.5,
+                1000,
+                (now - Time::from_days(3)).inner(),
+            ),
+            // hammer: 긴 아래꼬리, 몸통은 위쪽
+            Ohlcv::new(
+                100.0,
+                102.5,
+                90.0,
+                102.0,
+                1000,
+                (now - Time::from_days(2)).inner(),
+            ),
+            // shooting star: 긴 위꼬리, 몸통은 아래쪽
+            Ohlcv::new(
+                100.0,
+                112.0,
+                99.8,
+                101.0,
+                1000,
+                (now - Time::from_days(1)).inner(),
+            ),
+        ];
+        let patterns = detect(&data);
+        assert!(patterns[0].contains(&Pattern::Doji));
+        assert!(!patterns[0].contains(&Pattern::Hammer));
+        assert!(patterns[1].contains(&Pattern::Hammer));
+        assert!(!patterns[1].contains(&Pattern::Doji));
+        assert!(patterns[2].contains(&Pattern::ShootingStar));
+        assert!(!patterns[2].contains(&Pattern::Hammer));
+        assert_eq!(Some(Trend::Up), Pattern::Hammer.direction());
+        assert_eq!(None, Pattern::Doji.direction());
+    }
+
+    #[test]
+    fn test_multi_bar_pattern() {
+        let now = Time::now().unwrap();
+        let data = vec![
+            Ohlcv::new(
+                110.0,
+                111.0,
+                99.0,
+                100.0,
+                1000,
+                (now - Time::from_days(9)).inner(),
+            ),
+            // bullish engulfing + outside bar
+            Ohlcv::new(
+                99.0,
+                113.0,
+                98.0,
+                112.0,
+                1000,
+                (now - Time::from_days(8)).inner(),
+            ),
+            // bearish harami + inside bar
+            Ohlcv::new(
+                108.0,
+                110.0,
+                104.0,
+                105.0,
+                1000,
+                (now - Time::from_days(7)).inner(),
+            ),
+            // morning star: 긴 음봉 → 작은 몸통 → 첫 몸통 절반 위로 닫는 양봉
+            Ohlcv::new(
+                110.0,
+                111.0,
+                99.0,
+                100.0,
+                1000,
+                (now - Time::from_days(6)).inner(),
+            ),
+            Ohlcv::new(
+                98.0,
+                100.0,
+                95.0,
+                98.5,
+                1000,
+                (now - Time::from_days(5)).inner(),
+            ),
+            Ohlcv::new(
+                99.0,
+                108.0,
+                98.5,
+                107.0,
+                1000,
+                (now - Time::from_days(4)).inner(),
+            ),
+            // three white soldiers
+            Ohlcv::new(
+                106.0,
+                115.0,
+                105.5,
+                114.0,
+                1000,
+                (now - Time::from_days(3)).inner(),
+            ),
+            Ohlcv::new(
+                112.0,
+                121.0,
+                111.5,
+                120.0,
+                1000,
+                (now - Time::from_days(2)).inner(),
+            ),
+        ];
+        let patterns = detect(&data);
+        assert!(patterns[1].contains(&Pattern::BullishEngulfing));
+        assert!(patterns[1].contains(&Pattern::OutsideBar));
+        assert!(patterns[2].contains(&Pattern::BearishHarami));
+        assert!(patterns[2].contains(&Pattern::InsideBar));
+        assert!(patterns[5].contains(&Pattern::MorningStar));
+        assert!(patterns[7].contains(&Pattern::ThreeWhiteSoldiers));
+        assert!(!patterns[7].contains(&Pattern::ThreeBlackCrows));
+
+        // 정렬되지 않은 입력도 epoch_time 순으로 판별
+        let mut reversed = data.clone();
+        reversed.reverse();
+        assert_eq!(patterns, detect(&reversed));
+
+        assert!(Pattern::detect::<Ohlcv>(&[], &PatternConfig::default()).is_err());
+    }
+
+    #[test]
+    fn test_divergence() {
+        let prices = [
+            105.0, 103.0, 100.0, 102.0, 108.0, 104.0, 101.0, 95.0, 99.0, 103.0, 112.0, 106.0, 104.0,
+        ];
+        // swing low: bar 2(100) → bar 7(95), swing high: bar 4(108) → bar 10(112)
+        let oscillator = [
+            -2.0, -4.0, -5.0, 3.0, 8.0, 2.0, -1.0, -3.0, 1.0, 4.0, 6.0, 2.0, 1.0,
+        ];
+        let price: Vec<Point> = prices
+            .iter()
+            .enumerate()
+            .map(|(i, value)| Point::new(*value, day(i)))
+            .collect();
+        let oscillator: Vec<Point> = oscillator
+            .iter()
+            .enumerate()
+            .map(|(i, value)| Point::new(*value, day(i)))
+            .collect();
+
+        let divergences = Divergence::detect(&price, &oscillator, 2, 2).unwrap();
+        assert_eq!(2, divergences.len());
+        assert_eq!(DivergenceKind::RegularBullish, divergences[0].kind);
+        assert_eq!(Trend::Up, divergences[0].direction());
+        assert_eq!(
+            (day(2), day(7)),
+            (
+                divergences[0].price.0.epoch_time,
+                divergences[0].price.1.epoch_time
+            )
+        );
+        assert_eq!(
+            (-5f64, -3f64),
+            (
+                divergences[0].oscillator.0.value,
+                divergences[0].oscillator.1.value
+            )
+        );
+        assert_eq!(DivergenceKind::RegularBearish, divergences[1].kind);
+        assert_eq!(
+            (108f64, 112f64),
+            (divergences[1].price.0.value, divergences[1].price.1.value)
+        );
+
+        // oscillator가 price와 같은 방향이면 divergence 아님
+        let confirming: Vec<Point> = price
+            .iter()
+            .map(|elem| Point::new(elem.value / 10f64, elem.epoch_time))
+            .collect();
+        assert!(
+            Divergence::detect(&price, &confirming, 2, 2)
+                .unwrap()
+                .is_empty()
+        );
+
+        // hidden divergence: price는 higher low, oscillator는 lower low
+        let mut hidden = price.clone();
+        hidden[7].value = 100.5;
+        hidden[8].value = 101.5;
+        assert!(
+            Divergence::detect(&hidden, &oscillator, 2, 2)
+                .unwrap()
+                .iter()
+                .all(|elem| elem.kind != DivergenceKind::HiddenBullish)
+        );
+        let mut lower_oscillator = oscillator.clone();
+        lower_oscillator[7].value = -6.0;
+        let divergences = Divergence::detect(&hidden, &lower_oscillator, 2, 2).unwrap();
+        assert_eq!(DivergenceKind::HiddenBullish, divergences[0].kind);
+    }
+}
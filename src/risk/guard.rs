@@ -0,0 +1,55 @@
+use crate::types::{data::Point, time::Time};
+
+/// blocks new trades when the risk limits are hit
+/// Elder's rules: risk at most 2% of the equity per trade,
+/// and stop trading when the losses in the period(a month) reach 6%
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RiskGuard {
+    per_trade: f64,
+    per_period: f64,
+    period: Time,
+    /// realised pnl of closed trades at their exit time
+    pnls: Vec<Point>,
+}
+
+impl RiskGuard {
+    /// `per_trade` and `per_period` are ratios of the equity
+    pub fn new(per_trade: f64, per_period: f64, period: Time) -> Self {
+        Self {
+            per_trade,
+            per_period,
+            period,
+            pnls: vec![],
+        }
+    }
+
+    /// 2% per trade, 6% per 30 days
+    pub fn elder() -> Self {
+        Self::new(0.02, 0.06, Time::from_days(30))
+    }
+
+    /// record the realised pnl of a closed trade
+    pub fn record(&mut self, pnl: f64, epoch_time: u128) {
+        self.pnls.push(Point::new(pnl, epoch_time));
+    }
+
+    /// net loss of the trades closed in the period ending at `epoch_time`, 0 if profitable
+    pub fn loss(&self, epoch_time: u128) -> f64 {
+        let start = epoch_time.saturating_sub(self.period.inner());
+        let pnl: f64 = self
+            .pnls
+            .iter()
+            .filter(|pnl| pnl.epoch_time > start && pnl.epoch_time <= epoch_time)
+            .map(|pnl| pnl.value)
+            .sum();
+        (-pnl).max(0f64)
+    }
+
+    /// whether a new trade risking `risk`(quantity * |entry - stop|) is allowed
+    /// `open_risk` is the risk of the positions already open, counted against the period limit
+    pub fn allows(&self, equity: f64, risk: f64, open_risk: f64, epoch_time: u128) -> bool {
+        risk <= equity * self.per_trade
+            && self.loss(epoch_time) + open_risk + risk <= equity * self.per_period
+    }
+}
@@ -0,0 +1,6 @@
+mod guard;
+mod sizing;
+mod test;
+
+pub use guard::RiskGuard;
+pub use sizing::{Instrument, PositionSize};
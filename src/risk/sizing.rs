@@ -0,0 +1,187 @@
+use crate::types::error::ToolkitError;
+
+/// trading constraints of an instrument
+/// 0 means no rounding, e.g. fractional quantity
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Instrument {
+    pub lot_size: f64,
+    pub tick_size: f64,
+}
+
+/// quantity to trade, named after the sizing method
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PositionSize {
+    FixedFractional(f64),
+    AtrStop(f64),
+    Kelly(f64),
+    VolatilityTarget(f64),
+}
+
+impl Instrument {
+    pub fn new(lot_size: f64, tick_size: f64) -> Self {
+        Self {
+            lot_size,
+            tick_size,
+        }
+    }
+
+    /// round down to a multiple of the lot size
+    pub fn round_quantity(&self, quantity: f64) -> f64 {
+        if self.lot_size <= 0f64 {
+            return quantity;
+        }
+        // 0.3 / 0.1 = 2.9999999999999996 같은 오차 보정
+        (quantity / self.lot_size + 1e-9).floor() * self.lot_size
+    }
+
+    /// round to the nearest tick
+    pub fn round_price(&self, price: f64) -> f64 {
+        if self.tick_size <= 0f64 {
+            return price;
+        }
+        (price / self.tick_size).round() * self.tick_size
+    }
+
+    /// round the stop to the tick away from the entry, so the risk isn't understated
+    pub fn round_stop(&self, entry: f64, stop: f64) -> f64 {
+        if self.tick_size <= 0f64 {
+            return stop;
+        }
+        let ticks = stop / self.tick_size;
+        if stop < entry {
+            (ticks + 1e-9).floor() * self.tick_size
+        } else {
+            (ticks - 1e-9).ceil() * self.tick_size
+        }
+    }
+
+    fn validate(&self) -> Result<(), ToolkitError> {
+        if self.lot_size < 0f64 || self.tick_size < 0f64 {
+            return Err(ToolkitError::InvalidData);
+        }
+        Ok(())
+    }
+}
+
+impl PositionSize {
+    pub fn inner(&self) -> f64 {
+        match self {
+            Self::FixedFractional(f)
+            | Self::AtrStop(f)
+            | Self::Kelly(f)
+            | Self::VolatilityTarget(f) => *f,
+        }
+    }
+
+    /// risk `fraction` of the equity between the entry and the stop
+    /// e.g. 0.02 for Elder's 2% rule
+    pub fn fixed_fractional(
+        equity: f64,
+        fraction: f64,
+        entry: f64,
+        stop: f64,
+        instrument: &Instrument,
+    ) -> Result<Self, ToolkitError> {
+        Ok(Self::FixedFractional(Self::risk_based(
+            equity, fraction, entry, stop, instrument,
+        )?))
+    }
+
+    /// fixed fractional risk with the stop `multiplier` ATRs away from the entry
+    /// `atr` is the latest value of `AverageTrueRange`, the stop is below the entry for long
+    pub fn atr_stop(
+        equity: f64,
+        fraction: f64,
+        entry: f64,
+        atr: f64,
+        multiplier: f64,
+        instrument: &Instrument,
+    ) -> Result<Self, ToolkitError> {
+        if !(atr > 0f64 && multiplier > 0f64) {
+            return Err(ToolkitError::InvalidData);
+        }
+        Ok(Self::AtrStop(Self::risk_based(
+            equity,
+            fraction,
+            entry,
+            entry - atr * multiplier,
+            instrument,
+        )?))
+    }
+
+    /// Kelly fraction of the equity, f = p - (1 - p) / b
+    /// `payoff_ratio`(b) is the average win / average loss, and `scale` shrinks the fraction(0.5 for half Kelly)
+    /// 0 if there's no edge
+    pub fn kelly(
+        equity: f64,
+        win_rate: f64,
+        payoff_ratio: f64,
+        scale: f64,
+        entry: f64,
+        instrument: &Instrument,
+    ) -> Result<Self, ToolkitError> {
+        if !(0f64..=1f64).contains(&win_rate) || payoff_ratio <= 0f64 || scale < 0f64 {
+            return Err(ToolkitError::InvalidData);
+        }
+        let fraction = (win_rate - (1f64 - win_rate) / payoff_ratio).max(0f64) * scale;
+        Ok(Self::Kelly(Self::notional_based(
+            equity, fraction, entry, instrument,
+        )?))
+    }
+
+    /// size the position so its annualised volatility matches the target
+    /// `volatility` is the annualised volatility of the instrument, e.g. from `Volatility`
+    pub fn volatility_target(
+        equity: f64,
+        target: f64,
+        volatility: f64,
+        entry: f64,
+        instrument: &Instrument,
+    ) -> Result<Self, ToolkitError> {
+        if target < 0f64 || volatility <= 0f64 {
+            return Err(ToolkitError::InvalidData);
+        }
+        Ok(Self::VolatilityTarget(Self::notional_based(
+            equity,
+            target / volatility,
+            entry,
+            instrument,
+        )?))
+    }
+
+    fn risk_based(
+        equity: f64,
+        fraction: f64,
+        entry: f64,
+        stop: f64,
+        instrument: &Instrument,
+    ) -> Result<f64, ToolkitError> {
+        instrument.validate()?;
+        if equity <= 0f64 || fraction < 0f64 || entry <= 0f64 || stop < 0f64 {
+            return Err(ToolkitError::InvalidData);
+        }
+        let entry = instrument.round_price(entry);
+        let stop = instrument.round_stop(entry, stop);
+        let risk_per_unit = (entry - stop).abs();
+        if risk_per_unit == 0f64 {
+            return Err(ToolkitError::InvalidData);
+        }
+        Ok(instrument.round_quantity(equity * fraction / risk_per_unit))
+    }
+
+    fn notional_based(
+        equity: f64,
+        fraction: f64,
+        entry: f64,
+        instrument: &Instrument,
+    ) -> Result<f64, ToolkitError> {
+        instrument.validate()?;
+        if equity <= 0f64 || entry <= 0f64 {
+            return Err(ToolkitError::InvalidData);
+        }
+        let entry = instrument.round_price(entry);
+        Ok(instrument.round_quantity(equity * fraction / entry))
+    }
+}
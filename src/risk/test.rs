@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use crate::risk::{Instrument, PositionSize, RiskGuard};
+    use crate::test_util::day;
+
+    static MAX_ERR: f64 = 0.0000000001f64;
+
+    #[test]
+    fn test_position_size() {
+        let instrument = Instrument::new(10f64, 0.01);
+        // entry 50.00, stop은 entry에서 먼 tick(47.99)으로 → 200 / 2.01 = 99.5 → 90
+        let size =
+            PositionSize::fixed_fractional(10000f64, 0.02, 50.004, 47.995, &instrument).unwrap();
+        assert_eq!(PositionSize::FixedFractional(90f64), size);
+        // short: stop이 entry 위
+        let size =
+            PositionSize::fixed_fractional(10000f64, 0.02, 50f64, 52f64, &instrument).unwrap();
+        assert!((size.inner() - 100f64).abs() < MAX_ERR);
+        assert!(PositionSize::fixed_fractional(10000f64, 0.02, 50f64, 50f64, &instrument).is_err());
+
+        let instrument = Instrument::new(1f64, 0.01);
+        // stop = 100 - 2.5 * 2 = 95
+        let size = PositionSize::atr_stop(10000f64, 0.02, 100f64, 2.5, 2f64, &instrument).unwrap();
+        assert!((size.inner() - 40f64).abs() < MAX_ERR);
+
+        // 0.55 - 0.45 / 1.5 = 0.25, half Kelly 0.125 → 1250 / 100 = 12.5 → 12
+        let size = PositionSize::kelly(10000f64, 0.55, 1.5, 0.5, 100f64, &instrument).unwrap();
+        assert!((size.inner() - 12f64).abs() < MAX_ERR);
+        // edge가 없으면 0
+        let size = PositionSize::kelly(10000f64, 0.3, 1f64, 1f64, 100f64, &instrument).unwrap();
+        assert_eq!(0f64, size.inner());
+
+        // 0.1 / 0.4 = 0.25 → 2500 / 100 = 25
+        let instrument = Instrument::new(0.5, 0f64);
+        let size =
+            PositionSize::volatility_target(10000f64, 0.1, 0.4, 100f64, &instrument).unwrap();
+        assert!((size.inner() - 25f64).abs() < MAX_ERR);
+        assert!((instrument.round_quantity(0.3 / 0.1 * 0.5) - 1.5).abs() < MAX_ERR);
+    }
+
+    #[test]
+    fn test_risk_guard() {
+        let mut guard = RiskGuard::elder();
+        let equity = 10000f64;
+        assert!(guard.allows(equity, 200f64, 0f64, day(0)));
+        assert!(!guard.allows(equity, 201f64, 0f64, day(0)));
+
+        guard.record(-200f64, day(1));
+        guard.record(-150f64, day(5));
+        guard.record(50f64, day(10));
+        // 누적 손실 300 + open risk 100 + 신규 200 = 600
+        assert!((guard.loss(day(10)) - 300f64).abs() < MAX_ERR);
+        assert!(guard.allows(equity, 200f64, 100f64, day(10)));
+        assert!(!guard.allows(equity, 200f64, 101f64, day(10)));
+
+        // day 1 손실은 30일 뒤 window에서 빠짐
+        assert!((guard.loss(day(31)) - 100f64).abs() < MAX_ERR);
+        assert_eq!(0f64, guard.loss(day(40)));
+    }
+}
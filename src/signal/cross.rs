@@ -0,0 +1,88 @@
+use crate::types::data::BaseData;
+use std::collections::HashMap;
+
+/// crossing of 2 series at an epoch time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Cross {
+    /// first series crossed above the second one
+    Over(u128),
+    /// first series crossed below the second one
+    Under(u128),
+}
+
+impl Cross {
+    pub fn epoch_time(&self) -> u128 {
+        match self {
+            Self::Over(epoch_time) | Self::Under(epoch_time) => *epoch_time,
+        }
+    }
+
+    /// crossings of `a` against `b`, values are paired by epoch time
+    /// e.g. MACD fast line crossing the signal, price crossing the upper band
+    pub fn detect<A, B>(a: &[A], b: &[B]) -> Vec<Self>
+    where
+        A: BaseData,
+        B: BaseData,
+    {
+        let b: HashMap<u128, f64> = b
+            .iter()
+            .map(|elem| (elem.epoch_time(), elem.value()))
+            .collect();
+        let mut pairs: Vec<(u128, f64)> = a
+            .iter()
+            .filter_map(|elem| {
+                b.get(&elem.epoch_time())
+                    .map(|value_b| (elem.epoch_time(), elem.value() - value_b))
+            })
+            .collect();
+        pairs.sort_by_key(|k| k.0);
+        Self::from_differences(&pairs)
+    }
+
+    /// crossings of `a` against a constant `level`
+    pub fn detect_level<A>(a: &[A], level: f64) -> Vec<Self>
+    where
+        A: BaseData,
+    {
+        let mut pairs: Vec<(u128, f64)> = a
+            .iter()
+            .map(|elem| (elem.epoch_time(), elem.value() - level))
+            .collect();
+        pairs.sort_by_key(|k| k.0);
+        Self::from_differences(&pairs)
+    }
+
+    /// crossing at the latest paired bar of `a` and `b`, if any
+    pub fn latest<A, B>(a: &[A], b: &[B]) -> Option<Self>
+    where
+        A: BaseData,
+        B: BaseData,
+    {
+        let latest_epoch_time = a
+            .iter()
+            .map(|elem| elem.epoch_time())
+            .filter(|epoch_time| b.iter().any(|elem| elem.epoch_time() == *epoch_time))
+            .max()?;
+        Self::detect(a, b)
+            .pop()
+            .filter(|cross| cross.epoch_time() == latest_epoch_time)
+    }
+
+    /// (epoch time, a - b) sorted by epoch time
+    fn from_differences(differences: &[(u128, f64)]) -> Vec<Self> {
+        differences
+            .windows(2)
+            .filter_map(|pair| {
+                let (prev, (epoch_time, curr)) = (pair[0].1, pair[1]);
+                if prev <= 0f64 && curr > 0f64 {
+                    Some(Self::Over(epoch_time))
+                } else if prev >= 0f64 && curr < 0f64 {
+                    Some(Self::Under(epoch_time))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,8 @@
+mod cross;
+mod series;
+mod test;
+mod threshold;
+
+pub use cross::Cross;
+pub use series::{bars_since, bars_since_epoch, falling, rising};
+pub use threshold::{Threshold, Zone};
@@ -0,0 +1,54 @@
+use crate::types::data::BaseData;
+
+/// whether the value has risen on each of the latest `bars` bars
+pub fn rising<T>(data: &[T], bars: usize) -> bool
+where
+    T: BaseData + Clone,
+{
+    latest_changes(data, bars).is_some_and(|changes| changes.iter().all(|change| *change > 0f64))
+}
+
+/// whether the value has fallen on each of the latest `bars` bars
+pub fn falling<T>(data: &[T], bars: usize) -> bool
+where
+    T: BaseData + Clone,
+{
+    latest_changes(data, bars).is_some_and(|changes| changes.iter().all(|change| *change < 0f64))
+}
+
+/// bars passed since the latest bar where `condition` holds, 0 if it holds on the latest bar
+pub fn bars_since<T, F>(data: &[T], condition: F) -> Option<usize>
+where
+    T: BaseData + Clone,
+    F: Fn(&T) -> bool,
+{
+    let mut data = data.to_vec();
+    data.sort_by_key(|k| k.epoch_time());
+    data.iter().rev().position(condition)
+}
+
+/// bars passed since the latest of `epoch_times`(e.g. of crosses) in the data
+pub fn bars_since_epoch<T>(data: &[T], epoch_times: &[u128]) -> Option<usize>
+where
+    T: BaseData + Clone,
+{
+    bars_since(data, |elem| epoch_times.contains(&elem.epoch_time()))
+}
+
+/// bar-to-bar changes of the latest `bars` bars, None if the data is not enough
+fn latest_changes<T>(data: &[T], bars: usize) -> Option<Vec<f64>>
+where
+    T: BaseData + Clone,
+{
+    if bars == 0 || data.len() <= bars {
+        return None;
+    }
+    let mut data = data.to_vec();
+    data.sort_by_key(|k| k.epoch_time());
+    Some(
+        data[data.len() - bars - 1..]
+            .windows(2)
+            .map(|pair| pair[1].value() - pair[0].value())
+            .collect(),
+    )
+}
@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests {
+    use crate::indicator::MovingAverage;
+    use crate::signal::{Cross, Threshold, Zone, bars_since, bars_since_epoch, falling, rising};
+    use crate::test_util::day;
+    use crate::types::data::Point;
+
+    fn series(values: &[f64]) -> Vec<Point> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| Point::new(*value, day(i)))
+            .collect()
+    }
+
+    #[test]
+    fn test_cross() {
+        let price = series(&[10.0, 11.0, 12.0, 11.0, 9.0, 8.0, 9.0, 11.0, 12.0, 13.0]);
+        // SMA(3)을 각 window의 마지막 bar 시각에 찍은 series
+        let sma: Vec<Point> = price
+            .windows(3)
+            .map(|window| {
+                Point::new(
+                    MovingAverage::simple(window).inner(),
+                    window.last().unwrap().epoch_time,
+                )
+            })
+            .collect();
+        // sma: 11, 11.33, 10.67, 9.33, 8.67, 9.33, 10.67, 12
+        let crosses = Cross::detect(&price, &sma);
+        assert_eq!(vec![Cross::Under(day(3)), Cross::Over(day(6))], crosses);
+        assert_eq!(None, Cross::latest(&price, &sma));
+        assert_eq!(Some(Cross::Over(day(6))), Cross::latest(&price[0..7], &sma));
+
+        // 같은 값에 닿았다가 넘어가면 한 번만 cross
+        let a = series(&[1.0, 2.0, 3.0]);
+        let b = series(&[2.0, 2.0, 2.0]);
+        assert_eq!(vec![Cross::Over(day(2))], Cross::detect(&a, &b));
+        assert_eq!(
+            vec![Cross::Over(day(1)), Cross::Under(day(3))],
+            Cross::detect_level(&series(&[9.0, 11.0, 12.0, 10.0]), 10.5)
+        );
+
+        let since = bars_since_epoch(&price, &[crosses[0].epoch_time()]);
+        assert_eq!(Some(6), since);
+    }
+
+    #[test]
+    fn test_cross_columnar() {
+        let price = series(&[10.0, 11.0, 12.0, 11.0, 9.0, 8.0, 9.0, 11.0, 12.0, 13.0]);
+        let epoch_times: Vec<u128> = price.iter().map(|elem| elem.epoch_time).collect();
+        let values: Vec<f64> = price.iter().map(|elem| elem.value).collect();
+        let fast = Point::series(
+            &epoch_times,
+            &MovingAverage::simple_columnar(&values, 2).unwrap(),
+        )
+        .unwrap();
+        let slow = Point::series(
+            &epoch_times,
+            &MovingAverage::simple_columnar(&values, 4).unwrap(),
+        )
+        .unwrap();
+        // NaN인 bar는 제외
+        assert_eq!(9, fast.len());
+        assert_eq!(7, slow.len());
+        assert_eq!(Point::new(11.0, day(3)), slow[0]);
+
+        // fast - slow: 0.5, -0.75, -1.5, -0.75, 0.75, 1.5, 1.25
+        assert_eq!(
+            vec![Cross::Under(day(4)), Cross::Over(day(7))],
+            Cross::detect(&fast, &slow)
+        );
+        assert!(Point::series(&epoch_times[1..], &values).is_err());
+    }
+
+    #[test]
+    fn test_threshold() {
+        let stochastic = series(&[50.0, 75.0, 85.0, 90.0, 78.0, 60.0, 15.0, 25.0]);
+        assert_eq!(
+            vec![Threshold::Enter(day(2)), Threshold::Exit(day(4))],
+            Threshold::detect(&stochastic, Zone::Above(80.0))
+        );
+        assert_eq!(
+            vec![Threshold::Enter(day(6)), Threshold::Exit(day(7))],
+            Threshold::detect(&stochastic, Zone::Below(20.0))
+        );
+    }
+
+    #[test]
+    fn test_series() {
+        let data = series(&[5.0, 4.0, 6.0, 7.0, 8.0]);
+        assert!(rising(&data, 3));
+        assert!(!rising(&data, 4));
+        assert!(!rising(&data, 5));
+        assert!(!falling(&data, 1));
+        assert!(falling(&data[0..2], 1));
+
+        assert_eq!(Some(0), bars_since(&data, |elem| elem.value > 7.0));
+        assert_eq!(Some(3), bars_since(&data, |elem| elem.value < 5.0));
+        assert_eq!(None, bars_since(&data, |elem| elem.value > 10.0));
+    }
+}
@@ -0,0 +1,57 @@
+use crate::types::data::BaseData;
+
+/// zone bounded by a threshold
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Zone {
+    /// values greater than the threshold, e.g. overbought Stochastic above 80
+    Above(f64),
+    /// values less than the threshold, e.g. oversold Stochastic below 20
+    Below(f64),
+}
+
+/// a series entering or leaving a zone at an epoch time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Threshold {
+    Enter(u128),
+    Exit(u128),
+}
+
+impl Zone {
+    pub fn contains(&self, value: f64) -> bool {
+        match self {
+            Self::Above(threshold) => value > *threshold,
+            Self::Below(threshold) => value < *threshold,
+        }
+    }
+}
+
+impl Threshold {
+    pub fn epoch_time(&self) -> u128 {
+        match self {
+            Self::Enter(epoch_time) | Self::Exit(epoch_time) => *epoch_time,
+        }
+    }
+
+    /// every time the data enters or leaves the zone
+    pub fn detect<T>(data: &[T], zone: Zone) -> Vec<Self>
+    where
+        T: BaseData + Clone,
+    {
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+        data.windows(2)
+            .filter_map(|pair| {
+                match (
+                    zone.contains(pair[0].value()),
+                    zone.contains(pair[1].value()),
+                ) {
+                    (false, true) => Some(Self::Enter(pair[1].epoch_time())),
+                    (true, false) => Some(Self::Exit(pair[1].epoch_time())),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,133 @@
+use crate::types::{data::BaseData, error::ToolkitError};
+use std::collections::HashMap;
+
+/// PairStats
+/// co-moments of two series, updated incrementally(Welford) when a pair
+/// enters or leaves the window
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PairStats {
+    count: usize,
+    mean_x: f64,
+    mean_y: f64,
+    m2_x: f64,
+    m2_y: f64,
+    c_xy: f64,
+}
+
+impl PairStats {
+    /// pairs `x` and `y` by epoch time, values without a pair are ignored
+    pub fn new<X, Y>(x: &[X], y: &[Y]) -> Result<Self, ToolkitError>
+    where
+        X: BaseData,
+        Y: BaseData,
+    {
+        if x.is_empty() || y.is_empty() {
+            return Err(ToolkitError::EmptyData);
+        }
+        let y: HashMap<u128, f64> = y
+            .iter()
+            .map(|elem| (elem.epoch_time(), elem.value()))
+            .collect();
+        let mut stats = Self::default();
+        for elem_x in x.iter() {
+            if let Some(value_y) = y.get(&elem_x.epoch_time()) {
+                stats = stats.add(elem_x.value(), *value_y);
+            }
+        }
+        if stats.count < 2 {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        Ok(stats)
+    }
+
+    /// PairStats of the window moved by 1 pair
+    pub fn from_prev<X, Y>(prev: &Self, oldest_data: (&X, &Y), new_data: (&X, &Y)) -> Self
+    where
+        X: BaseData,
+        Y: BaseData,
+    {
+        prev.remove(oldest_data.0.value(), oldest_data.1.value())
+            .add(new_data.0.value(), new_data.1.value())
+    }
+
+    /// PairStats with (`x`, `y`) entered the window
+    pub fn add(&self, x: f64, y: f64) -> Self {
+        let n = (self.count + 1) as f64;
+        let delta_x = x - self.mean_x;
+        let delta_y = y - self.mean_y;
+        let mean_x = self.mean_x + delta_x / n;
+        let mean_y = self.mean_y + delta_y / n;
+        Self {
+            count: self.count + 1,
+            mean_x,
+            mean_y,
+            m2_x: self.m2_x + delta_x * (x - mean_x),
+            m2_y: self.m2_y + delta_y * (y - mean_y),
+            c_xy: self.c_xy + delta_x * (y - mean_y),
+        }
+    }
+
+    /// PairStats with (`x`, `y`) left the window
+    /// inverse of `add()`
+    pub fn remove(&self, x: f64, y: f64) -> Self {
+        if self.count <= 1 {
+            return Self::default();
+        }
+        let n = self.count as f64;
+        let mean_x = (n * self.mean_x - x) / (n - 1f64);
+        let mean_y = (n * self.mean_y - y) / (n - 1f64);
+        Self {
+            count: self.count - 1,
+            mean_x,
+            mean_y,
+            m2_x: (self.m2_x - (x - mean_x) * (x - self.mean_x)).max(0f64),
+            m2_y: (self.m2_y - (y - mean_y) * (y - self.mean_y)).max(0f64),
+            c_xy: self.c_xy - (x - mean_x) * (y - self.mean_y),
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn mean_x(&self) -> f64 {
+        self.mean_x
+    }
+
+    pub fn mean_y(&self) -> f64 {
+        self.mean_y
+    }
+
+    /// population variance of x
+    pub fn variance_x(&self) -> f64 {
+        self.m2_x / self.count.max(1) as f64
+    }
+
+    /// population variance of y
+    pub fn variance_y(&self) -> f64 {
+        self.m2_y / self.count.max(1) as f64
+    }
+
+    /// population covariance
+    pub fn covariance(&self) -> f64 {
+        self.c_xy / self.count.max(1) as f64
+    }
+
+    /// Pearson correlation coefficient
+    pub fn correlation(&self) -> f64 {
+        let denominator = (self.m2_x * self.m2_y).sqrt();
+        if denominator == 0f64 {
+            return 0f64;
+        }
+        self.c_xy / denominator
+    }
+
+    /// beta of y against x(the benchmark)
+    pub fn beta(&self) -> f64 {
+        if self.m2_x == 0f64 {
+            return 0f64;
+        }
+        self.c_xy / self.m2_x
+    }
+}
@@ -0,0 +1,8 @@
+mod correlation;
+mod regression;
+mod rolling;
+mod test;
+
+pub use correlation::PairStats;
+pub use regression::LinearRegression;
+pub use rolling::RollingStats;
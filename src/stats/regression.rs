@@ -0,0 +1,96 @@
+use super::PairStats;
+use crate::types::{data::BaseData, error::ToolkitError};
+
+/// LinearRegression
+/// least squares line of values against bar index
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinearRegression {
+    stats: PairStats,
+    /// bar index of the latest data
+    last_x: f64,
+    epoch_time: u128,
+}
+
+impl BaseData for LinearRegression {
+    fn value(&self) -> f64 {
+        self.end()
+    }
+
+    fn weight(&self) -> u64 {
+        1
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.epoch_time
+    }
+}
+
+impl LinearRegression {
+    pub fn new<T>(data: &[T]) -> Result<Self, ToolkitError>
+    where
+        T: BaseData + Clone,
+    {
+        if data.len() < 2 {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        let mut data = data.to_vec();
+        data.sort_by_key(|k| k.epoch_time());
+        let stats = data
+            .iter()
+            .enumerate()
+            .fold(PairStats::default(), |stats, (i, elem)| {
+                stats.add(i as f64, elem.value())
+            });
+        Ok(Self {
+            stats,
+            last_x: (data.len() - 1) as f64,
+            epoch_time: data.last().unwrap().epoch_time(), // it's safe since the vector's length > 1
+        })
+    }
+
+    /// LinearRegression of the window moved by 1 bar
+    pub fn from_prev<T>(prev: &Self, oldest_data: &T, new_data: &T) -> Self
+    where
+        T: BaseData,
+    {
+        let oldest_x = prev.last_x - (prev.stats.count() - 1) as f64;
+        let last_x = prev.last_x + 1f64;
+        Self {
+            stats: prev
+                .stats
+                .remove(oldest_x, oldest_data.value())
+                .add(last_x, new_data.value()),
+            last_x,
+            epoch_time: new_data.epoch_time(),
+        }
+    }
+
+    /// change of value per bar
+    pub fn slope(&self) -> f64 {
+        self.stats.beta()
+    }
+
+    /// value of the line at the oldest bar
+    pub fn intercept(&self) -> f64 {
+        let oldest_x = self.last_x - (self.stats.count() - 1) as f64;
+        self.stats.mean_y() - self.slope() * (self.stats.mean_x() - oldest_x)
+    }
+
+    /// value of the line at the latest bar
+    pub fn end(&self) -> f64 {
+        self.stats.mean_y() + self.slope() * (self.last_x - self.stats.mean_x())
+    }
+
+    /// coefficient of determination
+    pub fn r_squared(&self) -> f64 {
+        self.stats.correlation().powi(2)
+    }
+
+    /// population standard deviation of the residuals
+    pub fn residual_stdev(&self) -> f64 {
+        (self.stats.variance_y() * (1f64 - self.r_squared()))
+            .max(0f64)
+            .sqrt()
+    }
+}
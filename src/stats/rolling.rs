@@ -0,0 +1,144 @@
+use crate::types::{data::BaseData, error::ToolkitError};
+
+/// RollingStats
+/// central moments of a window, updated incrementally(Welford/Pébay) when a value
+/// enters or leaves the window
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingStats {
+    count: usize,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+}
+
+impl RollingStats {
+    pub fn new<T>(data: &[T]) -> Result<Self, ToolkitError>
+    where
+        T: BaseData,
+    {
+        if data.is_empty() {
+            return Err(ToolkitError::EmptyData);
+        }
+        Ok(data
+            .iter()
+            .fold(Self::default(), |stats, elem| stats.add(elem.value())))
+    }
+
+    /// RollingStats of the window moved by 1 bar
+    pub fn from_prev<T>(prev: &Self, oldest_data: &T, new_data: &T) -> Self
+    where
+        T: BaseData,
+    {
+        prev.remove(oldest_data.value()).add(new_data.value())
+    }
+
+    /// RollingStats with `value` entered the window
+    pub fn add(&self, value: f64) -> Self {
+        let n = (self.count + 1) as f64;
+        let delta = value - self.mean;
+        let delta_n = delta / n;
+        let term = delta * delta_n * (n - 1f64);
+        Self {
+            count: self.count + 1,
+            mean: self.mean + delta_n,
+            m2: self.m2 + term,
+            m3: self.m3 + term * delta_n * (n - 2f64) - 3f64 * delta_n * self.m2,
+            m4: self.m4
+                + term * delta_n * delta_n * (n * n - 3f64 * n + 3f64)
+                + 6f64 * delta_n * delta_n * self.m2
+                - 4f64 * delta_n * self.m3,
+        }
+    }
+
+    /// RollingStats with `value` left the window
+    /// inverse of `add()`
+    pub fn remove(&self, value: f64) -> Self {
+        if self.count <= 1 {
+            return Self::default();
+        }
+        let n = self.count as f64;
+        let mean = (n * self.mean - value) / (n - 1f64);
+        let delta = value - mean;
+        let delta_n = delta / n;
+        let term = delta * delta_n * (n - 1f64);
+        let m2 = self.m2 - term;
+        let m3 = self.m3 - term * delta_n * (n - 2f64) + 3f64 * delta_n * m2;
+        let m4 = self.m4
+            - term * delta_n * delta_n * (n * n - 3f64 * n + 3f64)
+            - 6f64 * delta_n * delta_n * m2
+            + 4f64 * delta_n * m3;
+        Self {
+            count: self.count - 1,
+            mean,
+            m2: m2.max(0f64),
+            m3,
+            m4: m4.max(0f64),
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// population variance
+    pub fn variance(&self) -> f64 {
+        if self.count == 0 {
+            return 0f64;
+        }
+        self.m2 / self.count as f64
+    }
+
+    /// sample(unbiased) variance
+    pub fn sample_variance(&self) -> f64 {
+        if self.count < 2 {
+            return 0f64;
+        }
+        self.m2 / (self.count - 1) as f64
+    }
+
+    /// population standard deviation
+    pub fn stdev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// sample standard deviation
+    pub fn sample_stdev(&self) -> f64 {
+        self.sample_variance().sqrt()
+    }
+
+    /// population standard deviation around `center` instead of the mean
+    pub fn stdev_around(&self, center: f64) -> f64 {
+        (self.variance() + (self.mean - center).powi(2)).sqrt()
+    }
+
+    /// how many standard deviations `value` is away from the mean
+    pub fn z_score(&self, value: f64) -> f64 {
+        let stdev = self.stdev();
+        if stdev == 0f64 {
+            return 0f64;
+        }
+        (value - self.mean) / stdev
+    }
+
+    /// population skewness
+    pub fn skewness(&self) -> f64 {
+        if self.m2 == 0f64 {
+            return 0f64;
+        }
+        (self.count as f64).sqrt() * self.m3 / self.m2.powf(1.5)
+    }
+
+    /// population excess kurtosis
+    pub fn kurtosis(&self) -> f64 {
+        if self.m2 == 0f64 {
+            return 0f64;
+        }
+        self.count as f64 * self.m4 / (self.m2 * self.m2) - 3f64
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use crate::indicator::Channel;
+    use crate::stats::{LinearRegression, PairStats, RollingStats};
+    use crate::types::{data::Point, time::Time};
+
+    static MAX_ERR: f64 = 0.0000000001f64;
+
+    #[test]
+    fn test_rolling_stats() {
+        let now = Time::now().unwrap();
+        let data = vec![
+            Point::new(1100.0, (now - Time::from_days(7)).inner()),
+            Point::new(1000.0, (now - Time::from_days(6)).inner()),
+            Point::new(1200.0, (now - Time::from_days(5)).inner()),
+            Point::new(1150.0, (now - Time::from_days(4)).inner()),
+            Point::new(1200.0, (now - Time::from_days(3)).inner()),
+            Point::new(1000.0, (now - Time::from_days(2)).inner()),
+            Point::new(900.0, (now - Time::from_days(1)).inner()),
+        ];
+        let stats = RollingStats::new(&data).unwrap();
+        assert_eq!(7, stats.count());
+        assert!((stats.mean() - 1078.5714285714287).abs() < MAX_ERR);
+        assert!((stats.variance() - 11326.530612244898).abs() < MAX_ERR);
+        assert!((stats.sample_variance() - 13214.285714285716).abs() < MAX_ERR);
+        assert!((stats.skewness() - (-0.3210665453321171)).abs() < MAX_ERR);
+        assert!((stats.kurtosis() - (-1.2694180667153634)).abs() < MAX_ERR);
+        assert!((stats.z_score(900f64) - (-1.6778901380350617)).abs() < MAX_ERR);
+
+        // window를 1 bar 이동한 결과가 새 window로 batch 계산한 결과와 같은지 검증
+        let rolled = RollingStats::from_prev(&stats, &data[0], &Point::new(950.0, now.inner()));
+        assert_eq!(7, rolled.count());
+        assert!((rolled.mean() - 1057.142857142857).abs() < MAX_ERR);
+        assert!((rolled.variance() - 13163.26530612245).abs() < MAX_ERR);
+        assert!((rolled.skewness() - 0.10858828017988875).abs() < MAX_ERR);
+        assert!((rolled.kurtosis() - (-1.6229493419866596)).abs() < MAX_ERR);
+
+        // 큰 offset에도 분산이 무너지지 않는지 검증
+        let shifted: Vec<Point> = data
+            .iter()
+            .map(|elem| Point::new(elem.value + 1e9, elem.epoch_time))
+            .collect();
+        let shifted_stats = RollingStats::new(&shifted).unwrap();
+        assert!((shifted_stats.variance() - stats.variance()).abs() < 1e-5);
+
+        assert!(RollingStats::new::<Point>(&[]).is_err());
+    }
+
+    #[test]
+    fn test_pair_stats() {
+        let now = Time::now().unwrap();
+        let benchmark = vec![
+            Point::new(100.0, (now - Time::from_days(7)).inner()),
+            Point::new(98.0, (now - Time::from_days(6)).inner()),
+            Point::new(103.0, (now - Time::from_days(5)).inner()),
+            Point::new(102.0, (now - Time::from_days(4)).inner()),
+            Point::new(104.0, (now - Time::from_days(3)).inner()),
+            Point::new(99.0, (now - Time::from_days(2)).inner()),
+            Point::new(95.0, (now - Time::from_days(1)).inner()),
+            // 짝이 없는 값은 무시
+            Point::new(10.0, now.inner()),
+        ];
+        let data = vec![
+            Point::new(1100.0, (now - Time::from_days(7)).inner()),
+            Point::new(1000.0, (now - Time::from_days(6)).inner()),
+            Point::new(1200.0, (now - Time::from_days(5)).inner()),
+            Point::new(1150.0, (now - Time::from_days(4)).inner()),
+            Point::new(1200.0, (now - Time::from_days(3)).inner()),
+            Point::new(1000.0, (now - Time::from_days(2)).inner()),
+            Point::new(900.0, (now - Time::from_days(1)).inner()),
+        ];
+        let stats = PairStats::new(&benchmark, &data).unwrap();
+        assert_eq!(7, stats.count());
+        assert!((stats.correlation() - 0.9820449214581491).abs() < MAX_ERR);
+        assert!((stats.beta() - 36.043689320388346).abs() < MAX_ERR);
+
+        let regression = LinearRegression::new(&data).unwrap();
+        assert!((regression.slope() - (-21.428571428571427)).abs() < MAX_ERR);
+        assert!((regression.intercept() - 1142.857142857143).abs() < MAX_ERR);
+        assert!((regression.end() - 1014.2857142857143).abs() < MAX_ERR);
+        assert!((regression.r_squared() - 0.16216216216216217).abs() < MAX_ERR);
+
+        let band = Channel::linear_regression(&data, 2f64).unwrap().inner();
+        assert!((band.mid - 1014.2857142857143).abs() < MAX_ERR);
+        assert!((band.upper - (1014.2857142857143 + 2f64 * 97.41558354989897)).abs() < MAX_ERR);
+
+        // window를 1 bar 이동한 결과가 새 window로 batch 계산한 결과와 같은지 검증
+        let new_data = Point::new(950.0, now.inner());
+        let rolled = LinearRegression::from_prev(&regression, &data[0], &new_data);
+        let mut moved = data[1..].to_vec();
+        moved.push(new_data);
+        let expected = LinearRegression::new(&moved).unwrap();
+        assert!((rolled.slope() - expected.slope()).abs() < MAX_ERR);
+        assert!((rolled.intercept() - expected.intercept()).abs() < MAX_ERR);
+        assert!((rolled.end() - expected.end()).abs() < MAX_ERR);
+        assert!((rolled.r_squared() - expected.r_squared()).abs() < MAX_ERR);
+    }
+}
@@ -0,0 +1,6 @@
+use crate::types::time::Time;
+
+/// epoch time of `days` days after 2024-10-04(day 20000)
+pub(crate) fn day(days: usize) -> u128 {
+    Time::from_days(20000 + days).inner()
+}
@@ -0,0 +1,8 @@
+mod order;
+mod portfolio;
+mod position;
+mod test;
+
+pub use order::{Fill, Order, OrderKind, Side, TimeInForce, Trade};
+pub use portfolio::Portfolio;
+pub use position::{CostBasis, Lot, Position};
@@ -0,0 +1,131 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Side {
+    Buy,
+    Sell,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OrderKind {
+    /// filled at the open of the next bar
+    Market,
+    /// filled at the limit price or better
+    Limit(f64),
+    /// becomes a market order when the stop price is touched
+    Stop(f64),
+    /// becomes a limit order when the stop price is touched
+    StopLimit { stop: f64, limit: f64 },
+}
+
+/// how long an order stays open
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeInForce {
+    /// valid for the next bar only
+    Day,
+    /// valid until filled
+    GoodTillCancel,
+    /// valid for the bars at or before the epoch time
+    GoodTillTime(u128),
+    /// filled at the open of the next bar or cancelled
+    ImmediateOrCancel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Order {
+    pub side: Side,
+    pub kind: OrderKind,
+    pub quantity: f64,
+    pub time_in_force: TimeInForce,
+}
+
+impl Order {
+    pub fn market(side: Side, quantity: f64) -> Self {
+        Self {
+            side,
+            kind: OrderKind::Market,
+            quantity,
+            time_in_force: TimeInForce::Day,
+        }
+    }
+
+    pub fn limit(side: Side, quantity: f64, price: f64) -> Self {
+        Self {
+            side,
+            kind: OrderKind::Limit(price),
+            quantity,
+            time_in_force: TimeInForce::Day,
+        }
+    }
+
+    pub fn stop(side: Side, quantity: f64, price: f64) -> Self {
+        Self {
+            side,
+            kind: OrderKind::Stop(price),
+            quantity,
+            time_in_force: TimeInForce::Day,
+        }
+    }
+
+    pub fn stop_limit(side: Side, quantity: f64, stop: f64, limit: f64) -> Self {
+        Self {
+            side,
+            kind: OrderKind::StopLimit { stop, limit },
+            quantity,
+            time_in_force: TimeInForce::Day,
+        }
+    }
+
+    pub fn with_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = time_in_force;
+        self
+    }
+
+    /// signed quantity, positive for buy
+    pub fn signed_quantity(&self) -> f64 {
+        match self.side {
+            Side::Buy => self.quantity,
+            Side::Sell => -self.quantity,
+        }
+    }
+}
+
+/// execution of an order
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fill {
+    pub epoch_time: u128,
+    pub side: Side,
+    pub quantity: f64,
+    /// price after spread and slippage
+    pub price: f64,
+    pub commission: f64,
+}
+
+/// round trip of a position
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trade {
+    /// `Buy` for a long trade, `Sell` for a short trade
+    pub side: Side,
+    pub quantity: f64,
+    pub entry_time: u128,
+    pub entry_price: f64,
+    pub exit_time: u128,
+    pub exit_price: f64,
+    /// commissions of the entry and exit fills attributed to this trade
+    pub commission: f64,
+}
+
+impl Trade {
+    /// profit and loss after commission
+    pub fn pnl(&self) -> f64 {
+        let gross = match self.side {
+            Side::Buy => (self.exit_price - self.entry_price) * self.quantity,
+            Side::Sell => (self.entry_price - self.exit_price) * self.quantity,
+        };
+        gross - self.commission
+    }
+}
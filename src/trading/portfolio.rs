@@ -0,0 +1,126 @@
+use super::{CostBasis, Fill, Position, Side, Trade};
+use crate::types::data::{Candle, Point};
+use std::collections::BTreeMap;
+
+/// cash and positions of multiple symbols
+/// positions are marked to the latest close, or the last fill price before the first candle
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Portfolio {
+    cash: f64,
+    /// margin required per unit of gross exposure, 1 for a cash account
+    margin_ratio: f64,
+    positions: BTreeMap<String, Position>,
+    /// latest price of each symbol
+    marks: BTreeMap<String, Point>,
+}
+
+impl Portfolio {
+    pub fn new(cash: f64, margin_ratio: f64) -> Self {
+        Self {
+            cash,
+            margin_ratio,
+            positions: BTreeMap::new(),
+            marks: BTreeMap::new(),
+        }
+    }
+
+    /// apply the fill of the symbol, returns trades closed by the fill(FIFO)
+    pub fn apply(&mut self, symbol: &str, fill: &Fill) -> Vec<Trade> {
+        let value = fill.quantity * fill.price;
+        self.cash -= match fill.side {
+            Side::Buy => value,
+            Side::Sell => -value,
+        } + fill.commission;
+        if !self.marks.contains_key(symbol) {
+            self.marks
+                .insert(symbol.to_string(), Point::new(fill.price, fill.epoch_time));
+        }
+        self.positions
+            .entry(symbol.to_string())
+            .or_default()
+            .apply(fill)
+    }
+
+    /// mark the symbol to the close of the candle
+    /// older candles than the current mark are ignored
+    pub fn mark<T>(&mut self, symbol: &str, candle: &T)
+    where
+        T: Candle,
+    {
+        let mark = Point::new(candle.close_price(), candle.epoch_time());
+        match self.marks.get_mut(symbol) {
+            Some(prev) if prev.epoch_time > mark.epoch_time => {}
+            Some(prev) => *prev = mark,
+            None => {
+                self.marks.insert(symbol.to_string(), mark);
+            }
+        }
+    }
+
+    pub fn cash(&self) -> f64 {
+        self.cash
+    }
+
+    pub fn position(&self, symbol: &str) -> Option<&Position> {
+        self.positions.get(symbol)
+    }
+
+    /// positions sorted by symbol
+    pub fn positions(&self) -> impl Iterator<Item = (&String, &Position)> {
+        self.positions.iter()
+    }
+
+    /// latest price of the symbol
+    pub fn price(&self, symbol: &str) -> Option<f64> {
+        self.marks.get(symbol).map(|mark| mark.value)
+    }
+
+    /// signed value of the positions, short positions are negative
+    pub fn market_value(&self) -> f64 {
+        self.marked()
+            .map(|(position, price)| position.market_value(price))
+            .sum()
+    }
+
+    /// cash + market value
+    pub fn equity(&self) -> f64 {
+        self.cash + self.market_value()
+    }
+
+    /// absolute value of the long and short positions
+    pub fn gross_exposure(&self) -> f64 {
+        self.marked()
+            .map(|(position, price)| position.market_value(price).abs())
+            .sum()
+    }
+
+    pub fn margin_used(&self) -> f64 {
+        self.gross_exposure() * self.margin_ratio
+    }
+
+    /// equity not used as margin
+    pub fn available_margin(&self) -> f64 {
+        self.equity() - self.margin_used()
+    }
+
+    pub fn realised_pnl(&self, basis: CostBasis) -> f64 {
+        self.positions
+            .values()
+            .map(|position| position.realised_pnl(basis))
+            .sum()
+    }
+
+    pub fn unrealised_pnl(&self, basis: CostBasis) -> f64 {
+        self.marked()
+            .map(|(position, price)| position.unrealised_pnl(basis, price))
+            .sum()
+    }
+
+    fn marked(&self) -> impl Iterator<Item = (&Position, f64)> {
+        self.positions.iter().map(|(symbol, position)| {
+            // it's safe since every symbol with a position is marked at its first fill
+            (position, self.marks[symbol].value)
+        })
+    }
+}
@@ -0,0 +1,146 @@
+use super::{Fill, Side, Trade};
+
+/// how the realised/unrealised pnl of a partially closed position is measured
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CostBasis {
+    /// oldest lots are closed first
+    Fifo,
+    /// every unit costs the average entry price
+    AverageCost,
+}
+
+/// open quantity entered at a fill
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lot {
+    pub epoch_time: u128,
+    /// signed quantity, negative for short
+    pub quantity: f64,
+    pub price: f64,
+    /// entry commission of the remaining quantity
+    pub commission: f64,
+}
+
+/// position of an instrument built from fills
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Position {
+    /// open lots, oldest first
+    lots: Vec<Lot>,
+    /// signed quantity, negative for short
+    quantity: f64,
+    average_cost: f64,
+    realised_fifo: f64,
+    realised_average: f64,
+}
+
+impl Position {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// apply the fill, returns trades closed by the fill(FIFO)
+    pub fn apply(&mut self, fill: &Fill) -> Vec<Trade> {
+        let mut remaining = match fill.side {
+            Side::Buy => fill.quantity,
+            Side::Sell => -fill.quantity,
+        };
+
+        let mut trades = vec![];
+        // 반대 방향 lot부터 FIFO로 청산
+        while let Some(lot) = self.lots.first_mut() {
+            if remaining == 0f64 || lot.quantity.signum() == remaining.signum() {
+                break;
+            }
+            let closed = lot.quantity.abs().min(remaining.abs());
+            // commission은 수량 비율로 배분
+            let entry_commission = lot.commission * closed / lot.quantity.abs();
+            let exit_commission = fill.commission * closed / fill.quantity;
+            let trade = Trade {
+                side: if lot.quantity > 0f64 {
+                    Side::Buy
+                } else {
+                    Side::Sell
+                },
+                quantity: closed,
+                entry_time: lot.epoch_time,
+                entry_price: lot.price,
+                exit_time: fill.epoch_time,
+                exit_price: fill.price,
+                commission: entry_commission + exit_commission,
+            };
+            self.realised_fifo += trade.pnl();
+            self.realised_average +=
+                (fill.price - self.average_cost) * closed * lot.quantity.signum()
+                    - trade.commission;
+            trades.push(trade);
+
+            lot.commission -= entry_commission;
+            lot.quantity -= closed * lot.quantity.signum();
+            remaining -= closed * remaining.signum();
+            self.quantity -= closed * self.quantity.signum();
+            if lot.quantity == 0f64 {
+                self.lots.remove(0);
+            }
+        }
+        // 부동소수점 잔량 정리
+        if self.lots.is_empty() {
+            self.quantity = 0f64;
+            self.average_cost = 0f64;
+        }
+        if remaining != 0f64 {
+            let quantity = self.quantity.abs() + remaining.abs();
+            self.average_cost =
+                (self.average_cost * self.quantity.abs() + fill.price * remaining.abs()) / quantity;
+            self.quantity += remaining;
+            self.lots.push(Lot {
+                epoch_time: fill.epoch_time,
+                quantity: remaining,
+                price: fill.price,
+                commission: fill.commission * remaining.abs() / fill.quantity,
+            });
+        }
+        trades
+    }
+
+    /// signed quantity, negative for short
+    pub fn quantity(&self) -> f64 {
+        self.quantity
+    }
+
+    /// average entry price of the open quantity
+    pub fn average_cost(&self) -> f64 {
+        self.average_cost
+    }
+
+    /// open lots, oldest first
+    pub fn lots(&self) -> &[Lot] {
+        &self.lots
+    }
+
+    /// signed value at the price, negative for short
+    pub fn market_value(&self, price: f64) -> f64 {
+        self.quantity * price
+    }
+
+    /// pnl of the closed quantity after commission
+    pub fn realised_pnl(&self, basis: CostBasis) -> f64 {
+        match basis {
+            CostBasis::Fifo => self.realised_fifo,
+            CostBasis::AverageCost => self.realised_average,
+        }
+    }
+
+    /// pnl of the open quantity marked at the price
+    pub fn unrealised_pnl(&self, basis: CostBasis, price: f64) -> f64 {
+        match basis {
+            CostBasis::Fifo => self
+                .lots
+                .iter()
+                .map(|lot| (price - lot.price) * lot.quantity)
+                .sum(),
+            CostBasis::AverageCost => (price - self.average_cost) * self.quantity,
+        }
+    }
+}
@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests {
+    use crate::test_util::day;
+    use crate::trading::{
+        CostBasis, Fill, Order, OrderKind, Portfolio, Position, Side, TimeInForce,
+    };
+    use crate::types::data::Ohlcv;
+
+    static MAX_ERR: f64 = 0.0000000001f64;
+
+    fn fill(side: Side, quantity: f64, price: f64, days: usize) -> Fill {
+        Fill {
+            epoch_time: day(days),
+            side,
+            quantity,
+            price,
+            commission: 0f64,
+        }
+    }
+
+    #[test]
+    fn test_order() {
+        let order = Order::stop_limit(Side::Buy, 10f64, 101f64, 101.5)
+            .with_time_in_force(TimeInForce::GoodTillCancel);
+        assert_eq!(
+            OrderKind::StopLimit {
+                stop: 101f64,
+                limit: 101.5
+            },
+            order.kind
+        );
+        assert_eq!(TimeInForce::GoodTillCancel, order.time_in_force);
+        assert_eq!(-3f64, Order::market(Side::Sell, 3f64).signed_quantity());
+        assert_eq!(
+            TimeInForce::Day,
+            Order::limit(Side::Buy, 1f64, 1f64).time_in_force
+        );
+    }
+
+    #[test]
+    fn test_position() {
+        let mut position = Position::new();
+        position.apply(&fill(Side::Buy, 10f64, 100f64, 0));
+        position.apply(&fill(Side::Buy, 10f64, 110f64, 1));
+        assert_eq!(20f64, position.quantity());
+        assert!((position.average_cost() - 105f64).abs() < MAX_ERR);
+
+        // FIFO: 10 @ 100, 5 @ 110 청산
+        let trades = position.apply(&fill(Side::Sell, 15f64, 120f64, 2));
+        assert_eq!(2, trades.len());
+        assert!((trades[0].pnl() - 200f64).abs() < MAX_ERR);
+        assert!((trades[1].pnl() - 50f64).abs() < MAX_ERR);
+        assert!((position.realised_pnl(CostBasis::Fifo) - 250f64).abs() < MAX_ERR);
+        assert!((position.realised_pnl(CostBasis::AverageCost) - 225f64).abs() < MAX_ERR);
+        assert!((position.unrealised_pnl(CostBasis::Fifo, 130f64) - 100f64).abs() < MAX_ERR);
+        assert!((position.unrealised_pnl(CostBasis::AverageCost, 130f64) - 125f64).abs() < MAX_ERR);
+
+        // 5 청산 후 5 short
+        position.apply(&fill(Side::Sell, 10f64, 100f64, 3));
+        assert_eq!(-5f64, position.quantity());
+        assert_eq!(100f64, position.average_cost());
+        assert_eq!(1, position.lots().len());
+        assert!((position.realised_pnl(CostBasis::Fifo) - 200f64).abs() < MAX_ERR);
+        assert!((position.realised_pnl(CostBasis::AverageCost) - 200f64).abs() < MAX_ERR);
+        assert!((position.unrealised_pnl(CostBasis::Fifo, 90f64) - 50f64).abs() < MAX_ERR);
+    }
+
+    #[test]
+    fn test_portfolio() {
+        let mut portfolio = Portfolio::new(10000f64, 0.5);
+        portfolio.apply("AAA", &fill(Side::Buy, 10f64, 100f64, 0));
+        portfolio.apply(
+            "BBB",
+            &Fill {
+                commission: 1f64,
+                ..fill(Side::Sell, 20f64, 50f64, 0)
+            },
+        );
+        assert!((portfolio.cash() - 9999f64).abs() < MAX_ERR);
+        assert!((portfolio.equity() - 9999f64).abs() < MAX_ERR);
+
+        portfolio.mark("AAA", &Ohlcv::new(105.0, 111.0, 104.0, 110.0, 1000, day(1)));
+        portfolio.mark("BBB", &Ohlcv::new(48.0, 49.0, 44.0, 45.0, 1000, day(1)));
+        // 이전 candle은 무시
+        portfolio.mark("AAA", &Ohlcv::new(95.0, 96.0, 89.0, 90.0, 1000, day(0)));
+        assert_eq!(Some(110f64), portfolio.price("AAA"));
+
+        // 1100 - 900
+        assert!((portfolio.market_value() - 200f64).abs() < MAX_ERR);
+        assert!((portfolio.equity() - 10199f64).abs() < MAX_ERR);
+        assert!((portfolio.gross_exposure() - 2000f64).abs() < MAX_ERR);
+        assert!((portfolio.margin_used() - 1000f64).abs() < MAX_ERR);
+        assert!((portfolio.available_margin() - 9199f64).abs() < MAX_ERR);
+        assert!((portfolio.unrealised_pnl(CostBasis::Fifo) - 200f64).abs() < MAX_ERR);
+
+        let trades = portfolio.apply("BBB", &fill(Side::Buy, 20f64, 45f64, 2));
+        assert_eq!(Side::Sell, trades[0].side);
+        assert!((portfolio.realised_pnl(CostBasis::Fifo) - 99f64).abs() < MAX_ERR);
+        assert_eq!(0f64, portfolio.position("BBB").unwrap().quantity());
+        assert!((portfolio.equity() - 10199f64).abs() < MAX_ERR);
+    }
+}
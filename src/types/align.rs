@@ -0,0 +1,115 @@
+use super::{data::BaseData, error::ToolkitError, time::Time};
+use std::collections::BTreeSet;
+
+/// how series with different epoch times are put on a common timeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Align {
+    /// every epoch time of any series, gaps are filled with the previous value
+    ForwardFill,
+    /// every epoch time of any series, gaps are left missing
+    DropMissing,
+    /// only the epoch times present in every series
+    InnerJoin,
+}
+
+/// values of the series at an epoch time, in the order of the given series
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aligned<T> {
+    pub epoch_time: u128,
+    /// None before the first value of the series(`ForwardFill`) or at a gap(`DropMissing`)
+    pub values: Vec<Option<T>>,
+}
+
+impl Align {
+    pub fn align<T>(&self, series: &[&[T]]) -> Result<Vec<Aligned<T>>, ToolkitError>
+    where
+        T: BaseData + Clone,
+    {
+        if series.is_empty() || series.iter().any(|data| data.is_empty()) {
+            return Err(ToolkitError::EmptyData);
+        }
+        let series: Vec<Vec<T>> = series
+            .iter()
+            .map(|data| {
+                let mut data = data.to_vec();
+                data.sort_by_key(|k| k.epoch_time());
+                data
+            })
+            .collect();
+        let timeline: BTreeSet<u128> = match self {
+            Self::ForwardFill | Self::DropMissing => series
+                .iter()
+                .flat_map(|data| data.iter().map(|elem| elem.epoch_time()))
+                .collect(),
+            Self::InnerJoin => series
+                .iter()
+                .map(|data| data.iter().map(|elem| elem.epoch_time()).collect())
+                .reduce(|common: BTreeSet<u128>, epoch_times| {
+                    common.intersection(&epoch_times).copied().collect()
+                })
+                .unwrap_or_default(),
+        };
+
+        // series별 다음에 볼 index
+        let mut cursors = vec![0usize; series.len()];
+        let mut latest: Vec<Option<T>> = vec![None; series.len()];
+        let mut aligned = Vec::with_capacity(timeline.len());
+        for epoch_time in timeline.into_iter() {
+            let mut values = Vec::with_capacity(series.len());
+            for (i, data) in series.iter().enumerate() {
+                let mut current = None;
+                // 같은 epoch time이 여러 개면 마지막 값 사용
+                while cursors[i] < data.len() && data[cursors[i]].epoch_time() <= epoch_time {
+                    if data[cursors[i]].epoch_time() == epoch_time {
+                        current = Some(data[cursors[i]].clone());
+                    }
+                    latest[i] = Some(data[cursors[i]].clone());
+                    cursors[i] += 1;
+                }
+                values.push(match self {
+                    Self::ForwardFill => latest[i].clone(),
+                    Self::DropMissing | Self::InnerJoin => current,
+                });
+            }
+            aligned.push(Aligned { epoch_time, values });
+        }
+        Ok(aligned)
+    }
+
+    /// value of the higher timeframe series(`length` per bar) known at each lower timeframe bar
+    /// epoch times are the bar opens, so a higher bar is used only once the lower bar closes at or after it
+    /// returns one value per lower bar sorted by epoch time, None until the first higher bar closes
+    pub fn higher_timeframe<H, L>(
+        higher: &[H],
+        length: Time,
+        lower: &[L],
+    ) -> Result<Vec<Option<H>>, ToolkitError>
+    where
+        H: BaseData + Clone,
+        L: BaseData,
+    {
+        if higher.is_empty() || lower.is_empty() {
+            return Err(ToolkitError::EmptyData);
+        }
+        let mut higher = higher.to_vec();
+        higher.sort_by_key(|k| k.epoch_time());
+        let mut lower: Vec<u128> = lower.iter().map(|elem| elem.epoch_time()).collect();
+        lower.sort();
+        let interval = Time::interval_of(&lower)?.inner();
+
+        let mut cursor = 0usize;
+        let mut known: Option<H> = None;
+        let mut mapped = Vec::with_capacity(lower.len());
+        for epoch_time in lower.iter() {
+            let close = epoch_time + interval;
+            while cursor < higher.len() && higher[cursor].epoch_time() + length.inner() <= close {
+                known = Some(higher[cursor].clone());
+                cursor += 1;
+            }
+            mapped.push(known.clone());
+        }
+        Ok(mapped)
+    }
+}
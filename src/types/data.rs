@@ -1,3 +1,5 @@
+use super::error::ToolkitError;
+
 pub trait BaseData {
     fn value(&self) -> f64;
     fn weight(&self) -> u64;
@@ -25,3 +27,248 @@ impl BaseData for dyn Candle {
         self.epoch_time()
     }
 }
+
+/// Point
+/// a single timestamped value, e.g. a line of an indicator
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point {
+    pub value: f64,
+    pub epoch_time: u128,
+}
+
+impl Point {
+    pub fn new(value: f64, epoch_time: u128) -> Self {
+        Self { value, epoch_time }
+    }
+
+    /// points of per-bar values, e.g. a `columnar` output with `CandleColumns::epoch_times`
+    /// NaN values(bars before the first complete period) are skipped
+    /// InvalidData if the lengths differ
+    pub fn series(epoch_times: &[u128], values: &[f64]) -> Result<Vec<Self>, ToolkitError> {
+        if epoch_times.len() != values.len() {
+            return Err(ToolkitError::InvalidData);
+        }
+        Ok(epoch_times
+            .iter()
+            .zip(values.iter())
+            .filter(|(_epoch_time, value)| !value.is_nan())
+            .map(|(epoch_time, value)| Self::new(*value, *epoch_time))
+            .collect())
+    }
+}
+
+impl BaseData for Point {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn weight(&self) -> u64 {
+        1
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.epoch_time
+    }
+}
+
+/// Ohlcv
+/// a candle with open/high/low/close prices and volume, e.g. a row of a csv file
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ohlcv {
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: u64,
+    pub epoch_time: u128,
+}
+
+impl Ohlcv {
+    pub fn new(open: f64, high: f64, low: f64, close: f64, volume: u64, epoch_time: u128) -> Self {
+        Self {
+            open,
+            high,
+            low,
+            close,
+            volume,
+            epoch_time,
+        }
+    }
+}
+
+impl Candle for Ohlcv {
+    fn open_price(&self) -> f64 {
+        self.open
+    }
+
+    fn high_price(&self) -> f64 {
+        self.high
+    }
+
+    fn low_price(&self) -> f64 {
+        self.low
+    }
+
+    fn close_price(&self) -> f64 {
+        self.close
+    }
+
+    fn tot_exec_volume(&self) -> u64 {
+        self.volume
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.epoch_time
+    }
+}
+
+impl BaseData for Ohlcv {
+    fn value(&self) -> f64 {
+        self.close
+    }
+
+    fn weight(&self) -> u64 {
+        self.volume
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.epoch_time
+    }
+}
+
+/// CandleColumns
+/// OHLCV columns of candles sorted by epoch time, e.g. from a columnar store
+/// the `columnar` functions of indicators read them without per-candle clones or sorting
+#[derive(Debug, Clone, Copy)]
+pub struct CandleColumns<'a> {
+    open: &'a [f64],
+    high: &'a [f64],
+    low: &'a [f64],
+    close: &'a [f64],
+    volume: &'a [u64],
+    epoch_times: &'a [u128],
+}
+
+/// a row of `CandleColumns`, usable wherever a `Candle` is expected
+#[derive(Debug, Clone, Copy)]
+pub struct ColumnCandle<'a> {
+    columns: CandleColumns<'a>,
+    index: usize,
+}
+
+impl<'a> CandleColumns<'a> {
+    /// InvalidData if the columns differ in length or the epoch times are not sorted
+    pub fn new(
+        open: &'a [f64],
+        high: &'a [f64],
+        low: &'a [f64],
+        close: &'a [f64],
+        volume: &'a [u64],
+        epoch_times: &'a [u128],
+    ) -> Result<Self, ToolkitError> {
+        let len = epoch_times.len();
+        if [open.len(), high.len(), low.len(), close.len(), volume.len()]
+            .iter()
+            .any(|column| *column != len)
+            || epoch_times.windows(2).any(|pair| pair[0] > pair[1])
+        {
+            return Err(ToolkitError::InvalidData);
+        }
+        Ok(Self {
+            open,
+            high,
+            low,
+            close,
+            volume,
+            epoch_times,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.epoch_times.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.epoch_times.is_empty()
+    }
+
+    pub fn open(&self) -> &'a [f64] {
+        self.open
+    }
+
+    pub fn high(&self) -> &'a [f64] {
+        self.high
+    }
+
+    pub fn low(&self) -> &'a [f64] {
+        self.low
+    }
+
+    pub fn close(&self) -> &'a [f64] {
+        self.close
+    }
+
+    pub fn volume(&self) -> &'a [u64] {
+        self.volume
+    }
+
+    pub fn epoch_times(&self) -> &'a [u128] {
+        self.epoch_times
+    }
+
+    /// row at `index`, panics if out of range like slice indexing
+    pub fn candle(&self, index: usize) -> ColumnCandle<'a> {
+        assert!(index < self.len(), "index out of range");
+        ColumnCandle {
+            columns: *self,
+            index,
+        }
+    }
+
+    /// every row in order
+    pub fn candles(&self) -> Vec<ColumnCandle<'a>> {
+        (0..self.len()).map(|index| self.candle(index)).collect()
+    }
+}
+
+impl Candle for ColumnCandle<'_> {
+    fn open_price(&self) -> f64 {
+        self.columns.open[self.index]
+    }
+
+    fn high_price(&self) -> f64 {
+        self.columns.high[self.index]
+    }
+
+    fn low_price(&self) -> f64 {
+        self.columns.low[self.index]
+    }
+
+    fn close_price(&self) -> f64 {
+        self.columns.close[self.index]
+    }
+
+    fn tot_exec_volume(&self) -> u64 {
+        self.columns.volume[self.index]
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.columns.epoch_times[self.index]
+    }
+}
+
+impl BaseData for ColumnCandle<'_> {
+    fn value(&self) -> f64 {
+        self.close_price()
+    }
+
+    fn weight(&self) -> u64 {
+        self.tot_exec_volume()
+    }
+
+    fn epoch_time(&self) -> u128 {
+        self.columns.epoch_times[self.index]
+    }
+}
@@ -8,4 +8,10 @@ pub enum ToolkitError {
     DataNotEnough,
     #[error("Data invalid")]
     InvalidData,
+    #[error("Parse error at line {line}: {message}")]
+    Parse { line: u64, message: String },
+    #[error("Schema error: {0}")]
+    Schema(String),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
 }
@@ -1,3 +1,5 @@
+pub mod align;
 pub mod data;
 pub mod error;
+mod test;
 pub mod time;
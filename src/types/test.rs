@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+    use crate::test_util::day;
+    use crate::types::{
+        align::{Align, Aligned},
+        data::Point,
+        time::Time,
+    };
+
+    fn values(aligned: &[Aligned<Point>], i: usize) -> Vec<Option<f64>> {
+        aligned
+            .iter()
+            .map(|row| row.values[i].map(|point| point.value))
+            .collect()
+    }
+
+    #[test]
+    fn test_align() {
+        let a: Vec<Point> = [(1.0, 0), (2.0, 1), (3.0, 2), (5.0, 4)]
+            .iter()
+            .map(|(value, days)| Point::new(*value, day(*days)))
+            .collect();
+        // 순서가 섞여 있어도 epoch time 순으로 정렬
+        let b: Vec<Point> = [(30.0, 3), (10.0, 1), (20.0, 2)]
+            .iter()
+            .map(|(value, days)| Point::new(*value, day(*days)))
+            .collect();
+
+        let aligned = Align::ForwardFill.align(&[&a, &b]).unwrap();
+        assert_eq!(
+            (0..5).map(day).collect::<Vec<u128>>(),
+            aligned
+                .iter()
+                .map(|row| row.epoch_time)
+                .collect::<Vec<u128>>()
+        );
+        assert_eq!(
+            vec![Some(1.0), Some(2.0), Some(3.0), Some(3.0), Some(5.0)],
+            values(&aligned, 0)
+        );
+        assert_eq!(
+            vec![None, Some(10.0), Some(20.0), Some(30.0), Some(30.0)],
+            values(&aligned, 1)
+        );
+        // forward fill된 값은 원래 epoch time 유지
+        assert_eq!(day(2), aligned[3].values[0].unwrap().epoch_time);
+
+        let aligned = Align::DropMissing.align(&[&a, &b]).unwrap();
+        assert_eq!(
+            vec![Some(1.0), Some(2.0), Some(3.0), None, Some(5.0)],
+            values(&aligned, 0)
+        );
+        assert_eq!(
+            vec![None, Some(10.0), Some(20.0), Some(30.0), None],
+            values(&aligned, 1)
+        );
+
+        let aligned = Align::InnerJoin.align(&[&a, &b]).unwrap();
+        assert_eq!(
+            vec![day(1), day(2)],
+            aligned
+                .iter()
+                .map(|row| row.epoch_time)
+                .collect::<Vec<u128>>()
+        );
+        assert_eq!(vec![Some(2.0), Some(3.0)], values(&aligned, 0));
+        assert_eq!(vec![Some(10.0), Some(20.0)], values(&aligned, 1));
+
+        assert!(Align::InnerJoin.align::<Point>(&[&a, &[]]).is_err());
+    }
+
+    #[test]
+    fn test_higher_timeframe() {
+        let hour = |hours: usize| Time::from_hours(480000 + hours).inner();
+        // 4시간 bar
+        let higher: Vec<Point> = [100.0, 200.0, 300.0]
+            .iter()
+            .enumerate()
+            .map(|(i, value)| Point::new(*value, hour(i * 4)))
+            .collect();
+        let lower: Vec<Point> = (0..10).map(|i| Point::new(1.0, hour(i))).collect();
+
+        let mapped = Align::higher_timeframe(&higher, Time::from_hours(4), &lower).unwrap();
+        let mapped: Vec<Option<f64>> = mapped
+            .iter()
+            .map(|value| value.map(|point| point.value))
+            .collect();
+        // 0시 4시간 bar는 3시 1시간 bar가 닫힐 때(4시) 확정
+        let mut expected = vec![None; 3];
+        expected.extend([Some(100.0); 4]);
+        expected.extend([Some(200.0); 3]);
+        assert_eq!(expected, mapped);
+    }
+}
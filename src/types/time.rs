@@ -1,4 +1,5 @@
-use std::ops::{Add, AddAssign, Sub, SubAssign};
+use super::error::ToolkitError;
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 static SEC_IN_MILLISEC: u128 = 1000u128;
@@ -26,6 +27,7 @@ static DAY_IN_MILLISEC: u128 = 24 * HOUR_IN_MILLISEC;
 /// assert_eq!(days_in_time.inner(), (days as u128) * 24 * 60 * 60 * 1000 as u128);
 /// ```
 #[derive(Debug, Eq, Clone, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Time(u128);
 
 impl From<u128> for Time {
@@ -34,7 +36,7 @@ impl From<u128> for Time {
     }
 }
 
-impl<'a> std::fmt::Display for Time {
+impl std::fmt::Display for Time {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.inner())
     }
@@ -73,6 +75,32 @@ impl Time {
     pub fn inner(&self) -> u128 {
         self.0
     }
+
+    /// return bar interval of a series as duration
+    /// it's the median of gaps between consecutive epoch times,
+    /// so it's not affected by occasional gaps(weekends, holidays, ...)
+    ///
+    /// example)
+    /// ```
+    /// use trading_toolkit::types::time::Time;
+    ///
+    /// let epoch_times = [0, 60_000, 120_000, 300_000, 360_000];
+    /// assert_eq!(Time::interval_of(&epoch_times).unwrap(), Time::from_minutes(1));
+    /// ```
+    pub fn interval_of(epoch_times: &[u128]) -> Result<Self, ToolkitError> {
+        let mut epoch_times = epoch_times.to_vec();
+        epoch_times.sort();
+        let mut gaps: Vec<u128> = epoch_times
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .filter(|gap| *gap > 0)
+            .collect();
+        if gaps.is_empty() {
+            return Err(ToolkitError::DataNotEnough);
+        }
+        gaps.sort();
+        Ok(Self(gaps[gaps.len() / 2]))
+    }
 }
 
 impl Add for Time {
@@ -89,6 +117,14 @@ impl AddAssign for Time {
     }
 }
 
+impl Mul<usize> for Time {
+    type Output = Self;
+
+    fn mul(self, rhs: usize) -> Self {
+        Self(self.0 * rhs as u128)
+    }
+}
+
 impl Sub for Time {
     type Output = Self;
 